@@ -0,0 +1,80 @@
+use super::{texture_atlas::Region, TextureAtlas};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Playback {
+    #[default]
+    Loop,
+    PingPong,
+    Once,
+}
+
+#[derive(Clone)]
+pub struct Animation {
+    pub atlas: TextureAtlas,
+    pub frames: Vec<(usize, Duration)>,
+    pub playback: Playback,
+}
+
+impl Animation {
+    pub fn new(atlas: TextureAtlas, frames: Vec<(usize, Duration)>, playback: Playback) -> Self {
+        Self {
+            atlas,
+            frames,
+            playback,
+        }
+    }
+
+    pub fn uniform<I: IntoIterator<Item = usize>>(
+        atlas: TextureAtlas,
+        frames: I,
+        duration: Duration,
+        playback: Playback,
+    ) -> Self {
+        let frames = frames.into_iter().map(|f| (f, duration)).collect();
+
+        Self::new(atlas, frames, playback)
+    }
+
+    pub fn from_atlas<I: IntoIterator<Item = usize>>(
+        atlas: TextureAtlas,
+        frames: I,
+        duration: Duration,
+        playback: Playback,
+    ) -> Self {
+        let frames = frames
+            .into_iter()
+            .map(|f| {
+                (
+                    f,
+                    atlas
+                        .durations
+                        .get(f)
+                        .cloned()
+                        .flatten()
+                        .unwrap_or(duration),
+                )
+            })
+            .collect();
+
+        Self::new(atlas, frames, playback)
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn region(&self, cursor: usize) -> Option<Region> {
+        self.frames
+            .get(cursor)
+            .and_then(|(f, _)| self.atlas.frame(*f))
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|(_, d)| *d).sum()
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    assets::{
+        bitmap_font::BmFontDescriptor, texture::TextureOptions, tiled::TiledDescriptor, BitmapFont,
+        Font, SamplerPreset, Shader, Texture, TiledMap, Upload,
+    },
+    Context,
+};
+use image::RgbaImage;
+use std::{hash::Hash, path::Path};
+
+pub trait Asset: Clone + Send + Sync + 'static {
+    type Settings: Clone + Default + Hash + Send + Sync + 'static;
+    type Source: Send + 'static;
+
+    fn decode(path: &Path, settings: &Self::Settings) -> anyhow::Result<Self::Source>;
+
+    fn upload(
+        context: &Context,
+        source: Self::Source,
+        settings: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>>;
+
+    fn reupload(
+        &self,
+        context: &Context,
+        source: Self::Source,
+        settings: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        Self::upload(context, source, settings)
+    }
+}
+
+impl Asset for Texture {
+    type Settings = (SamplerPreset, TextureOptions);
+    type Source = RgbaImage;
+
+    fn decode(path: &Path, _: &Self::Settings) -> anyhow::Result<Self::Source> {
+        Texture::decode(path)
+    }
+
+    fn upload(
+        context: &Context,
+        image: Self::Source,
+        (preset, options): &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        Texture::upload(
+            context,
+            context.sampler(*preset)?,
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            *options,
+        )
+    }
+
+    fn reupload(
+        &self,
+        context: &Context,
+        image: Self::Source,
+        settings: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        let (_, options) = settings;
+
+        match self.update(
+            context,
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            *options,
+        ) {
+            Ok(upload) => Ok(upload.map(|_| self.clone())),
+            Err(_) => <Self as Asset>::upload(context, image, settings),
+        }
+    }
+}
+
+impl Asset for Shader {
+    type Settings = ();
+    type Source = (String, Vec<u32>);
+
+    fn decode(path: &Path, _: &Self::Settings) -> anyhow::Result<Self::Source> {
+        Ok((path.display().to_string(), Shader::decode(path)?))
+    }
+
+    fn upload(
+        context: &Context,
+        (name, spirv): Self::Source,
+        _: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        Ok(Upload::ready(Self::from_spirv(context, &spirv, &name)?))
+    }
+
+    fn reupload(
+        &self,
+        context: &Context,
+        source: Self::Source,
+        settings: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        let shader = Self::upload(context, source, settings)?;
+
+        context.evict_pipelines(&self.entry_point);
+
+        Ok(shader)
+    }
+}
+
+impl Asset for Font {
+    type Settings = ();
+    type Source = Self;
+
+    fn decode(path: &Path, _: &Self::Settings) -> anyhow::Result<Self::Source> {
+        Self::from_file(path)
+    }
+
+    fn upload(_: &Context, font: Self::Source, _: &Self::Settings) -> anyhow::Result<Upload<Self>> {
+        Ok(Upload::ready(font))
+    }
+}
+
+impl Asset for BitmapFont {
+    type Settings = SamplerPreset;
+    type Source = (BmFontDescriptor, Vec<RgbaImage>);
+
+    fn decode(path: &Path, _: &Self::Settings) -> anyhow::Result<Self::Source> {
+        BitmapFont::decode(path)
+    }
+
+    fn upload(
+        context: &Context,
+        (descriptor, pages): Self::Source,
+        preset: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        BitmapFont::upload(context, context.sampler(*preset)?, &descriptor, &pages)
+    }
+}
+
+impl Asset for TiledMap {
+    type Settings = SamplerPreset;
+    type Source = (TiledDescriptor, Vec<RgbaImage>);
+
+    fn decode(path: &Path, _: &Self::Settings) -> anyhow::Result<Self::Source> {
+        TiledMap::decode(path)
+    }
+
+    fn upload(
+        context: &Context,
+        (descriptor, images): Self::Source,
+        preset: &Self::Settings,
+    ) -> anyhow::Result<Upload<Self>> {
+        TiledMap::upload(context, context.sampler(*preset)?, descriptor, &images)
+    }
+}
@@ -0,0 +1,8 @@
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssetEvent {
+    Loaded(PathBuf),
+    Reloaded(PathBuf),
+    Failed(PathBuf, String),
+}
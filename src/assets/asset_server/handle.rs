@@ -0,0 +1,116 @@
+use crate::assets::Upload;
+use parking_lot::RwLock;
+use std::{path::PathBuf, sync::Arc};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoadState {
+    Loading,
+    Loaded,
+    Failed(String),
+}
+
+pub struct Slot<T> {
+    pub path: PathBuf,
+    pub state: LoadState,
+    pub asset: Option<T>,
+    pub pending: Option<Upload<T>>,
+}
+
+pub struct Handle<T> {
+    pub(crate) slot: Arc<RwLock<Slot<T>>>,
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<T> Handle<T> {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self {
+            slot: Arc::new(RwLock::new(Slot {
+                path,
+                state: LoadState::Loading,
+                asset: None,
+                pending: None,
+            })),
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.slot.read().path.clone()
+    }
+
+    pub fn state(&self) -> LoadState {
+        self.poll();
+
+        self.slot.read().state.clone()
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.state() == LoadState::Loaded
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.state(), LoadState::Failed(_))
+    }
+
+    pub(crate) fn finish(&self, result: anyhow::Result<Upload<T>>) {
+        let mut slot = self.slot.write();
+
+        match result {
+            Ok(upload) if upload.is_ready() => {
+                slot.asset = Some(upload.into_value());
+                slot.pending = None;
+                slot.state = LoadState::Loaded;
+            }
+            Ok(upload) => {
+                slot.pending = Some(upload);
+            }
+            Err(e) => {
+                slot.pending = None;
+                slot.state = LoadState::Failed(format!("{}: {e:#}", slot.path.display()));
+            }
+        }
+    }
+
+    fn poll(&self) {
+        if !self
+            .slot
+            .read()
+            .pending
+            .as_ref()
+            .is_some_and(Upload::is_ready)
+        {
+            return;
+        }
+
+        let mut slot = self.slot.write();
+
+        if let Some(upload) = slot.pending.take() {
+            slot.asset = Some(upload.into_value());
+            slot.state = LoadState::Loaded;
+        }
+    }
+}
+
+impl<T> From<T> for Handle<T> {
+    fn from(asset: T) -> Self {
+        let handle = Self::new(PathBuf::new());
+
+        handle.finish(Ok(Upload::ready(asset)));
+
+        handle
+    }
+}
+
+impl<T: Clone> Handle<T> {
+    pub fn get(&self) -> Option<T> {
+        self.poll();
+
+        self.slot.read().asset.clone()
+    }
+}
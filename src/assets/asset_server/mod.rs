@@ -0,0 +1,308 @@
+pub mod asset;
+pub mod event;
+pub mod handle;
+
+pub use asset::Asset;
+pub use event::AssetEvent;
+pub use handle::{Handle, LoadState};
+
+use crate::{assets::Upload, Context};
+use handle::Slot;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    any::{Any, TypeId},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Weak,
+    },
+};
+use threadpool::ThreadPool;
+
+trait AssetEntry: Send + Sync + 'static {
+    fn as_any(&self) -> &(dyn Any + Send + Sync + 'static);
+
+    fn is_alive(&self) -> bool;
+
+    fn reload(&self, context: Arc<RwLock<Context>>, pool: &ThreadPool, events: Events);
+}
+
+type Events = Arc<Mutex<VecDeque<(AssetEvent, Upload<()>)>>>;
+
+const MAX_EVENTS: usize = 256;
+
+fn push(events: &Events, event: (AssetEvent, Upload<()>)) {
+    if let (AssetEvent::Failed(_, error), _) = &event {
+        eprintln!("{error}");
+    }
+
+    let mut events = events.lock();
+
+    if events.len() >= MAX_EVENTS {
+        events.pop_front();
+    }
+
+    events.push_back(event);
+}
+
+fn finish<T: Asset>(
+    handle: &Handle<T>,
+    result: anyhow::Result<Upload<T>>,
+    events: &Events,
+    event: fn(PathBuf) -> AssetEvent,
+) {
+    let path = handle.path();
+    let event = match &result {
+        Ok(upload) => (event(path), upload.clone().map(|_| ())),
+        Err(e) => (
+            AssetEvent::Failed(path.clone(), format!("{}: {e:#}", path.display())),
+            Upload::ready(()),
+        ),
+    };
+
+    handle.finish(result);
+    push(events, event);
+}
+
+struct Entry<T: Asset> {
+    slot: Weak<RwLock<Slot<T>>>,
+    settings: T::Settings,
+}
+
+impl<T: Asset> AssetEntry for Entry<T> {
+    fn as_any(&self) -> &(dyn Any + Send + Sync + 'static) {
+        self
+    }
+
+    fn is_alive(&self) -> bool {
+        self.slot.upgrade().is_some_and(|slot| {
+            let slot = slot.read();
+
+            slot.asset.is_some() || !matches!(slot.state, LoadState::Failed(_))
+        })
+    }
+
+    fn reload(&self, context: Arc<RwLock<Context>>, pool: &ThreadPool, events: Events) {
+        let Some(slot) = self.slot.upgrade() else {
+            return;
+        };
+        let settings = self.settings.clone();
+
+        pool.execute(move || {
+            let handle = Handle { slot };
+            let path = handle.path();
+            let result = T::decode(&path, &settings).and_then(|source| {
+                let context = context.read();
+
+                match handle.get() {
+                    Some(asset) => asset.reupload(&context, source, &settings),
+                    None => T::upload(&context, source, &settings),
+                }
+            });
+
+            finish(&handle, result, &events, AssetEvent::Reloaded);
+        });
+    }
+}
+
+type AssetKey = (TypeId, PathBuf, u64);
+
+pub struct AssetServer {
+    assets: HashMap<AssetKey, Box<dyn AssetEntry>>,
+    pool: ThreadPool,
+    watcher: Option<(
+        RecommendedWatcher,
+        Mutex<Receiver<notify::Result<notify::Event>>>,
+    )>,
+    watched: HashSet<PathBuf>,
+    events: Events,
+}
+
+impl AssetServer {
+    pub fn new(threads: usize) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            assets: HashMap::new(),
+            pool: ThreadPool::new(threads.max(1)),
+            watcher: None,
+            watched: HashSet::new(),
+            events: Events::default(),
+        }))
+    }
+
+    pub fn load<T: Asset, P: AsRef<Path>>(
+        &mut self,
+        context: Arc<RwLock<Context>>,
+        path: P,
+    ) -> Handle<T> {
+        self.load_with(context, path, T::Settings::default())
+    }
+
+    pub fn load_with<T: Asset, P: AsRef<Path>>(
+        &mut self,
+        context: Arc<RwLock<Context>>,
+        path: P,
+        settings: T::Settings,
+    ) -> Handle<T> {
+        self.cleanup();
+
+        let key = Self::key::<T>(path.as_ref(), &settings);
+
+        if let Some(handle) = self.entry::<T>(&key) {
+            return handle;
+        }
+
+        let path = key.1.clone();
+        let handle = Handle::<T>::new(path.clone());
+
+        self.assets.insert(
+            key,
+            Box::new(Entry::<T> {
+                slot: Arc::downgrade(&handle.slot),
+                settings: settings.clone(),
+            }),
+        );
+
+        if let Err(e) = self.watch_path(&path) {
+            push(
+                &self.events,
+                (
+                    AssetEvent::Failed(
+                        path.clone(),
+                        format!("Failed to watch {}: {e:#}", path.display()),
+                    ),
+                    Upload::ready(()),
+                ),
+            );
+        }
+
+        {
+            let handle = handle.clone();
+            let events = self.events.clone();
+
+            self.pool.execute(move || {
+                let result = T::decode(&path, &settings)
+                    .and_then(|source| T::upload(&context.read(), source, &settings));
+
+                finish(&handle, result, &events, AssetEvent::Loaded);
+            });
+        }
+
+        handle
+    }
+
+    pub fn get<T: Asset, P: AsRef<Path>>(&self, path: P) -> Option<Handle<T>> {
+        self.get_with(path, &T::Settings::default())
+    }
+
+    pub fn get_with<T: Asset, P: AsRef<Path>>(
+        &self,
+        path: P,
+        settings: &T::Settings,
+    ) -> Option<Handle<T>> {
+        self.entry(&Self::key::<T>(path.as_ref(), settings))
+    }
+
+    fn entry<T: Asset>(&self, key: &AssetKey) -> Option<Handle<T>> {
+        let slot = self
+            .assets
+            .get(key)?
+            .as_any()
+            .downcast_ref::<Entry<T>>()?
+            .slot
+            .upgrade()?;
+
+        Some(Handle { slot })
+    }
+
+    fn key<T: Asset>(path: &Path, settings: &T::Settings) -> AssetKey {
+        let mut hasher = DefaultHasher::new();
+
+        settings.hash(&mut hasher);
+
+        (
+            TypeId::of::<T>(),
+            path.canonicalize().unwrap_or_else(|_| path.to_path_buf()),
+            hasher.finish(),
+        )
+    }
+
+    pub fn take_events(&mut self) -> Vec<AssetEvent> {
+        let mut events = self.events.lock();
+        let (ready, pending): (VecDeque<_>, _) =
+            events.drain(..).partition(|(_, upload)| upload.is_ready());
+
+        *events = pending;
+
+        ready.into_iter().map(|(event, _)| event).collect()
+    }
+
+    pub fn cleanup(&mut self) {
+        self.assets.retain(|_, a| a.is_alive());
+    }
+
+    pub fn wait(&self) {
+        self.pool.join();
+    }
+
+    pub fn watch(&mut self) -> anyhow::Result<()> {
+        if self.watcher.is_none() {
+            let (sender, receiver) = mpsc::channel();
+
+            self.watcher = Some((notify::recommended_watcher(sender)?, Mutex::new(receiver)));
+
+            let paths: Vec<_> = self.assets.keys().map(|(_, p, _)| p.clone()).collect();
+
+            for path in paths {
+                self.watch_path(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn update(&mut self, context: Arc<RwLock<Context>>) {
+        let Some((_, receiver)) = &self.watcher else {
+            return;
+        };
+        let changed: HashSet<_> = receiver
+            .lock()
+            .try_iter()
+            .filter_map(Result::ok)
+            .filter(|e| matches!(e.kind, EventKind::Create(_) | EventKind::Modify(_)))
+            .flat_map(|e| e.paths)
+            .filter_map(|p| p.canonicalize().ok())
+            .collect();
+
+        if changed.is_empty() {
+            return;
+        }
+
+        self.cleanup();
+
+        for ((_, path, _), entry) in &self.assets {
+            if changed.contains(path) {
+                entry.reload(context.clone(), &self.pool, self.events.clone());
+            }
+        }
+    }
+
+    fn watch_path(&mut self, path: &Path) -> anyhow::Result<()> {
+        let Some((watcher, _)) = &mut self.watcher else {
+            return Ok(());
+        };
+        let dir = path
+            .canonicalize()?
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        if self.watched.insert(dir.clone()) {
+            watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+use anyhow::Context as _;
+use std::{collections::HashMap, str::FromStr};
+
+struct Element {
+    tag: String,
+    attributes: HashMap<String, String>,
+}
+
+impl Element {
+    fn get<T: FromStr>(&self, key: &str) -> anyhow::Result<T> {
+        self.attributes
+            .get(key)
+            .and_then(|v| v.parse().ok())
+            .with_context(|| format!("BMFont {} has a missing or invalid {key}", self.tag))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BmChar {
+    pub id: u32,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub xoffset: i32,
+    pub yoffset: i32,
+    pub xadvance: i32,
+    pub page: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BmKerning {
+    pub first: u32,
+    pub second: u32,
+    pub amount: i32,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BmFontDescriptor {
+    pub line_height: u32,
+    pub base: u32,
+    pub scale_w: u32,
+    pub scale_h: u32,
+    pub pages: Vec<String>,
+    pub chars: Vec<BmChar>,
+    pub kernings: Vec<BmKerning>,
+}
+
+impl BmFontDescriptor {
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        if data.starts_with(b"BMF") {
+            return Self::parse_binary(data);
+        }
+
+        let text = std::str::from_utf8(data).context("BMFont descriptor is not valid UTF-8")?;
+
+        if text.trim_start().starts_with('<') {
+            Self::parse_xml(text)
+        } else {
+            Self::parse_text(text)
+        }
+    }
+
+    pub fn parse_text(text: &str) -> anyhow::Result<Self> {
+        Self::from_elements(text.lines().filter_map(|line| {
+            let mut tokens = Self::tokenize(line).into_iter();
+            let tag = tokens.next()?;
+            let attributes = tokens
+                .filter_map(|t| {
+                    let (k, v) = t.split_once('=')?;
+
+                    Some((k.to_string(), v.trim_matches('"').to_string()))
+                })
+                .collect();
+
+            Some(Element { tag, attributes })
+        }))
+    }
+
+    pub fn parse_xml(text: &str) -> anyhow::Result<Self> {
+        let document = roxmltree::Document::parse(text).context("Failed to parse BMFont XML")?;
+
+        Self::from_elements(document.descendants().filter(|n| n.is_element()).map(|n| {
+            Element {
+                tag: n.tag_name().name().to_string(),
+                attributes: n
+                    .attributes()
+                    .iter()
+                    .map(|a| (a.name().to_string(), a.value().to_string()))
+                    .collect(),
+            }
+        }))
+    }
+
+    pub fn parse_binary(data: &[u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            data.len() >= 4 && data[3] == 3,
+            "Unsupported BMFont binary version"
+        );
+
+        let mut descriptor = Self::default();
+        let mut rest = &data[4..];
+
+        while !rest.is_empty() {
+            anyhow::ensure!(rest.len() >= 5, "Truncated BMFont block header");
+
+            let kind = rest[0];
+            let size = u32::from_le_bytes([rest[1], rest[2], rest[3], rest[4]]) as usize;
+            let block = rest.get(5..5 + size).context("Truncated BMFont block")?;
+            let u16_at = |b: &[u8], i: usize| u16::from_le_bytes([b[i], b[i + 1]]) as u32;
+            let i16_at = |b: &[u8], i: usize| i16::from_le_bytes([b[i], b[i + 1]]) as i32;
+            let u32_at =
+                |b: &[u8], i: usize| u32::from_le_bytes([b[i], b[i + 1], b[i + 2], b[i + 3]]);
+
+            match kind {
+                2 => {
+                    anyhow::ensure!(block.len() >= 10, "Truncated BMFont common block");
+
+                    descriptor.line_height = u16_at(block, 0);
+                    descriptor.base = u16_at(block, 2);
+                    descriptor.scale_w = u16_at(block, 4);
+                    descriptor.scale_h = u16_at(block, 6);
+                }
+                3 => {
+                    descriptor.pages = block
+                        .split(|&b| b == 0)
+                        .filter(|p| !p.is_empty())
+                        .map(|p| String::from_utf8_lossy(p).into_owned())
+                        .collect();
+                }
+                4 => {
+                    descriptor.chars = block
+                        .chunks_exact(20)
+                        .map(|c| BmChar {
+                            id: u32_at(c, 0),
+                            x: u16_at(c, 4),
+                            y: u16_at(c, 6),
+                            width: u16_at(c, 8),
+                            height: u16_at(c, 10),
+                            xoffset: i16_at(c, 12),
+                            yoffset: i16_at(c, 14),
+                            xadvance: i16_at(c, 16),
+                            page: c[18] as usize,
+                        })
+                        .collect();
+                }
+                5 => {
+                    descriptor.kernings = block
+                        .chunks_exact(10)
+                        .map(|k| BmKerning {
+                            first: u32_at(k, 0),
+                            second: u32_at(k, 4),
+                            amount: i16_at(k, 8),
+                        })
+                        .collect();
+                }
+                _ => {}
+            }
+
+            rest = &rest[5 + size..];
+        }
+
+        Ok(descriptor)
+    }
+
+    fn from_elements<I: IntoIterator<Item = Element>>(elements: I) -> anyhow::Result<Self> {
+        let mut descriptor = Self::default();
+
+        for element in elements {
+            match element.tag.as_str() {
+                "common" => {
+                    descriptor.line_height = element.get("lineHeight")?;
+                    descriptor.base = element.get("base")?;
+                    descriptor.scale_w = element.get("scaleW")?;
+                    descriptor.scale_h = element.get("scaleH")?;
+                }
+                "page" => {
+                    let id: usize = element.get("id")?;
+                    let file = element
+                        .attributes
+                        .get("file")
+                        .context("BMFont page is missing a file")?;
+
+                    if descriptor.pages.len() <= id {
+                        descriptor.pages.resize(id + 1, String::new());
+                    }
+
+                    descriptor.pages[id] = file.clone();
+                }
+                "char" => descriptor.chars.push(BmChar {
+                    id: element.get("id")?,
+                    x: element.get("x")?,
+                    y: element.get("y")?,
+                    width: element.get("width")?,
+                    height: element.get("height")?,
+                    xoffset: element.get("xoffset")?,
+                    yoffset: element.get("yoffset")?,
+                    xadvance: element.get("xadvance")?,
+                    page: element.get("page")?,
+                }),
+                "kerning" => descriptor.kernings.push(BmKerning {
+                    first: element.get("first")?,
+                    second: element.get("second")?,
+                    amount: element.get("amount")?,
+                }),
+                _ => {}
+            }
+        }
+
+        Ok(descriptor)
+    }
+
+    fn tokenize(line: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut token = String::new();
+        let mut quoted = false;
+
+        for c in line.chars() {
+            match c {
+                '"' => {
+                    quoted = !quoted;
+
+                    token.push(c);
+                }
+                c if c.is_whitespace() && !quoted => {
+                    if !token.is_empty() {
+                        tokens.push(std::mem::take(&mut token));
+                    }
+                }
+                c => token.push(c),
+            }
+        }
+
+        if !token.is_empty() {
+            tokens.push(token);
+        }
+
+        tokens
+    }
+}
@@ -0,0 +1,157 @@
+pub mod descriptor;
+
+pub use descriptor::BmFontDescriptor;
+
+use super::{texture::TextureOptions, texture_atlas::Region, Texture, Upload};
+use crate::Context;
+use anyhow::Context as _;
+use image::RgbaImage;
+use nalgebra::Vector2;
+use std::{collections::HashMap, path::Path, sync::Arc};
+use vulkano::image::sampler::Sampler;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BitmapGlyph {
+    pub region: Region,
+    pub offset: Vector2<f32>,
+    pub size: Vector2<f32>,
+    pub advance: f32,
+    pub page: usize,
+}
+
+#[derive(Clone)]
+pub struct BitmapFont {
+    pub line_height: f32,
+    pub base: f32,
+    pub pages: Vec<Texture>,
+    pub glyphs: HashMap<char, BitmapGlyph>,
+    pub kerning: HashMap<(char, char), f32>,
+}
+
+impl BitmapFont {
+    pub fn new(descriptor: &BmFontDescriptor, pages: Vec<Texture>) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            pages.len() == descriptor.pages.len(),
+            "BMFont has {} pages but {} textures were given",
+            descriptor.pages.len(),
+            pages.len()
+        );
+
+        let dimensions = Vector2::new(descriptor.scale_w, descriptor.scale_h);
+        let glyphs = descriptor
+            .chars
+            .iter()
+            .filter_map(|c| {
+                let glyph = BitmapGlyph {
+                    region: Region::from_pixels(
+                        Vector2::new(c.x, c.y),
+                        Vector2::new(c.width, c.height),
+                        dimensions,
+                    ),
+                    offset: Vector2::new(c.xoffset, c.yoffset).cast(),
+                    size: Vector2::new(c.width, c.height).cast(),
+                    advance: c.xadvance as f32,
+                    page: c.page,
+                };
+
+                Some((char::from_u32(c.id)?, glyph))
+            })
+            .collect();
+        let kerning = descriptor
+            .kernings
+            .iter()
+            .filter_map(|k| {
+                Some((
+                    (char::from_u32(k.first)?, char::from_u32(k.second)?),
+                    k.amount as f32,
+                ))
+            })
+            .collect();
+
+        Ok(Self {
+            line_height: descriptor.line_height as f32,
+            base: descriptor.base as f32,
+            pages,
+            glyphs,
+            kerning,
+        })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        path: P,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .with_context(|| format!("Failed to read bitmap font {}", path.display()))?;
+        let descriptor = BmFontDescriptor::parse(&data)
+            .with_context(|| format!("Failed to parse bitmap font {}", path.display()))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let pages = descriptor
+            .pages
+            .iter()
+            .map(|page| {
+                Texture::from_file(
+                    context,
+                    sampler.clone(),
+                    dir.join(page),
+                    TextureOptions::default(),
+                )
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Self::new(&descriptor, pages)
+    }
+
+    pub fn decode<P: AsRef<Path>>(path: P) -> anyhow::Result<(BmFontDescriptor, Vec<RgbaImage>)> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .with_context(|| format!("Failed to read bitmap font {}", path.display()))?;
+        let descriptor = BmFontDescriptor::parse(&data)
+            .with_context(|| format!("Failed to parse bitmap font {}", path.display()))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let pages = descriptor
+            .pages
+            .iter()
+            .map(|page| Texture::decode(dir.join(page)))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok((descriptor, pages))
+    }
+
+    pub fn upload(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        descriptor: &BmFontDescriptor,
+        pages: &[RgbaImage],
+    ) -> anyhow::Result<Upload<Self>> {
+        let pages = pages
+            .iter()
+            .map(|page| {
+                Texture::upload(
+                    context,
+                    sampler.clone(),
+                    page.as_raw(),
+                    page.width(),
+                    page.height(),
+                    TextureOptions::default(),
+                )
+            })
+            .collect::<anyhow::Result<Upload<Vec<_>>>>()?;
+        let font = Self::new(descriptor, pages.value().clone())?;
+
+        Ok(pages.map(|_| font))
+    }
+
+    pub fn glyph(&self, c: char) -> Option<&BitmapGlyph> {
+        self.glyphs.get(&c)
+    }
+
+    pub fn kerning(&self, first: char, second: char) -> f32 {
+        self.kerning
+            .get(&(first, second))
+            .copied()
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    assets::{texture::TextureOptions, texture_atlas::Region, SamplerPreset, Texture},
+    Context,
+};
+use ab_glyph::{Font as _, FontArc, GlyphId};
+use nalgebra::Vector2;
+use std::collections::HashMap;
+
+const PADDING: u32 = 1;
+
+#[derive(Clone, Copy, Debug)]
+pub struct AtlasGlyph {
+    pub region: Region,
+    pub offset: Vector2<f32>,
+    pub size: Vector2<f32>,
+}
+
+pub struct GlyphAtlas {
+    size: u32,
+    pixels: Vec<u8>,
+    cursor: Vector2<u32>,
+    shelf: u32,
+    glyphs: HashMap<(GlyphId, u32), Option<AtlasGlyph>>,
+    generation: u64,
+    dirty: bool,
+    texture: Option<Texture>,
+}
+
+impl GlyphAtlas {
+    pub fn new(size: u32) -> Self {
+        Self {
+            size,
+            pixels: Self::clear_pixels(size),
+            cursor: Vector2::zeros(),
+            shelf: 0,
+            glyphs: HashMap::new(),
+            generation: 0,
+            dirty: true,
+            texture: None,
+        }
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn glyph(
+        &mut self,
+        font: &FontArc,
+        id: GlyphId,
+        size: f32,
+    ) -> anyhow::Result<Option<AtlasGlyph>> {
+        let key = (id, size.to_bits());
+
+        if let Some(glyph) = self.glyphs.get(&key) {
+            return Ok(*glyph);
+        }
+
+        let glyph = match font.outline_glyph(id.with_scale(size)) {
+            Some(outlined) => {
+                let bounds = outlined.px_bounds();
+                let dims = Vector2::new(bounds.width() as u32, bounds.height() as u32);
+                let position = self.allocate(dims)?;
+                let stride = self.size;
+                let pixels = &mut self.pixels;
+
+                outlined.draw(|x, y, c| {
+                    let i = ((position.y + y) * stride + position.x + x) as usize * 4;
+
+                    pixels[i + 3] = (c.clamp(0.0, 1.0) * 255.0) as u8;
+                });
+
+                self.dirty = true;
+
+                Some(AtlasGlyph {
+                    region: Region::from_pixels(position, dims, Vector2::repeat(self.size)),
+                    offset: Vector2::new(bounds.min.x, bounds.min.y),
+                    size: dims.cast(),
+                })
+            }
+            None => None,
+        };
+
+        self.glyphs.insert(key, glyph);
+
+        Ok(glyph)
+    }
+
+    pub fn texture(&mut self, context: &Context) -> anyhow::Result<Texture> {
+        let texture = match self.texture.take() {
+            Some(texture) => {
+                if self.dirty {
+                    texture.update(
+                        context,
+                        &self.pixels,
+                        self.size,
+                        self.size,
+                        TextureOptions::default(),
+                    )?;
+                }
+
+                texture
+            }
+            None => Texture::upload(
+                context,
+                context.sampler(SamplerPreset::LINEAR)?,
+                &self.pixels,
+                self.size,
+                self.size,
+                TextureOptions::default(),
+            )?
+            .into_value(),
+        };
+
+        self.dirty = false;
+        self.texture = Some(texture.clone());
+
+        Ok(texture)
+    }
+
+    fn allocate(&mut self, dims: Vector2<u32>) -> anyhow::Result<Vector2<u32>> {
+        anyhow::ensure!(
+            dims.x + PADDING <= self.size && dims.y + PADDING <= self.size,
+            "Glyph of {}x{} does not fit in a {}x{} atlas",
+            dims.x,
+            dims.y,
+            self.size,
+            self.size
+        );
+
+        if self.cursor.x + dims.x + PADDING > self.size {
+            self.cursor = Vector2::new(0, self.cursor.y + self.shelf);
+            self.shelf = 0;
+        }
+
+        if self.cursor.y + dims.y + PADDING > self.size {
+            self.reset();
+        }
+
+        let position = self.cursor;
+
+        self.cursor.x += dims.x + PADDING;
+        self.shelf = self.shelf.max(dims.y + PADDING);
+
+        Ok(position)
+    }
+
+    fn reset(&mut self) {
+        self.pixels = Self::clear_pixels(self.size);
+        self.cursor = Vector2::zeros();
+        self.shelf = 0;
+        self.glyphs.clear();
+        self.generation += 1;
+        self.dirty = true;
+    }
+
+    fn clear_pixels(size: u32) -> Vec<u8> {
+        [255, 255, 255, 0].repeat(size as usize * size as usize)
+    }
+}
@@ -0,0 +1,33 @@
+pub mod glyph_atlas;
+
+pub use glyph_atlas::{AtlasGlyph, GlyphAtlas};
+
+use ab_glyph::FontArc;
+use anyhow::Context as _;
+use parking_lot::Mutex;
+use std::{path::Path, sync::Arc};
+
+const ATLAS_SIZE: u32 = 1024;
+
+#[derive(Clone)]
+pub struct Font {
+    pub font: FontArc,
+    pub atlas: Arc<Mutex<GlyphAtlas>>,
+}
+
+impl Font {
+    pub fn new(data: Vec<u8>) -> anyhow::Result<Self> {
+        Ok(Self {
+            font: FontArc::try_from_vec(data)?,
+            atlas: Arc::new(Mutex::new(GlyphAtlas::new(ATLAS_SIZE))),
+        })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)
+            .with_context(|| format!("Failed to read font {}", path.display()))?;
+
+        Self::new(data).with_context(|| format!("Failed to parse font {}", path.display()))
+    }
+}
@@ -0,0 +1,87 @@
+use super::{Handle, Shader, Texture};
+use nalgebra::{Matrix4, Vector2, Vector3, Vector4};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaterialParam {
+    Int(i32),
+    Float(f32),
+    Vec2(Vector2<f32>),
+    Vec3(Vector3<f32>),
+    Vec4(Vector4<f32>),
+    Mat4(Matrix4<f32>),
+}
+
+impl MaterialParam {
+    pub fn align(&self) -> usize {
+        match self {
+            Self::Int(_) | Self::Float(_) => 4,
+            Self::Vec2(_) => 8,
+            Self::Vec3(_) | Self::Vec4(_) | Self::Mat4(_) => 16,
+        }
+    }
+
+    pub fn bytes(&self) -> Vec<u8> {
+        let floats: &[f32] = match self {
+            Self::Int(v) => return v.to_ne_bytes().to_vec(),
+            Self::Float(v) => std::slice::from_ref(v),
+            Self::Vec2(v) => v.as_slice(),
+            Self::Vec3(v) => v.as_slice(),
+            Self::Vec4(v) => v.as_slice(),
+            Self::Mat4(v) => v.as_slice(),
+        };
+
+        floats.iter().flat_map(|f| f.to_ne_bytes()).collect()
+    }
+
+    pub fn uniform_bytes<'a>(params: impl IntoIterator<Item = &'a MaterialParam>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for p in params {
+            bytes.resize(bytes.len().next_multiple_of(p.align()), 0);
+            bytes.extend(p.bytes());
+        }
+
+        bytes.resize(bytes.len().next_multiple_of(16), 0);
+
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct Material {
+    pub shader: Handle<Shader>,
+    pub params: Vec<(String, MaterialParam)>,
+    pub textures: Vec<Texture>,
+}
+
+impl Material {
+    pub fn new(
+        shader: Handle<Shader>,
+        params: Vec<(String, MaterialParam)>,
+        textures: Vec<Texture>,
+    ) -> Self {
+        Self {
+            shader,
+            params,
+            textures,
+        }
+    }
+
+    pub fn param(&self, name: &str) -> Option<MaterialParam> {
+        self.params
+            .iter()
+            .find_map(|(n, p)| (n == name).then_some(*p))
+    }
+
+    pub fn set_param(&mut self, name: &str, value: MaterialParam) -> bool {
+        self.params
+            .iter_mut()
+            .find(|(n, _)| n == name)
+            .map(|(_, p)| *p = value)
+            .is_some()
+    }
+
+    pub fn uniform_bytes(&self) -> Vec<u8> {
+        MaterialParam::uniform_bytes(self.params.iter().map(|(_, p)| p))
+    }
+}
@@ -0,0 +1,20 @@
+use nalgebra::{Vector2, Vector4};
+use vulkano::{buffer::BufferContents, pipeline::graphics::vertex_input::Vertex};
+
+#[derive(BufferContents, Vertex, Copy, Clone)]
+#[repr(C)]
+pub struct ColorVertex2 {
+    #[format(R32G32_SFLOAT)]
+    pub position: [f32; 2],
+    #[format(R32G32B32A32_SFLOAT)]
+    pub color: [f32; 4],
+}
+
+impl ColorVertex2 {
+    pub fn new(position: Vector2<f32>, color: Vector4<f32>) -> Self {
+        Self {
+            position: position.into(),
+            color: color.into(),
+        }
+    }
+}
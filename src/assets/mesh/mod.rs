@@ -0,0 +1,107 @@
+pub mod color_vertex2;
+
+pub use color_vertex2::ColorVertex2;
+
+use super::{
+    shape::{triangulate, Bounds},
+    Shape,
+};
+use crate::Context;
+use nalgebra::{Vector2, Vector4};
+use vulkano::{
+    buffer::{subbuffer::Subbuffer, BufferUsage},
+    pipeline::graphics::input_assembly::PrimitiveTopology,
+};
+
+#[derive(Clone)]
+pub struct Mesh {
+    pub vertices: Subbuffer<[ColorVertex2]>,
+    pub indices: Option<Subbuffer<[u32]>>,
+    pub topology: PrimitiveTopology,
+    pub bounds: Bounds,
+}
+
+impl Mesh {
+    pub fn new(
+        context: &Context,
+        vertices: &[ColorVertex2],
+        indices: Option<&[u32]>,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Self> {
+        if let Some(indices) = indices {
+            anyhow::ensure!(
+                indices.iter().all(|&i| (i as usize) < vertices.len()),
+                "Mesh index out of range for {} vertices",
+                vertices.len()
+            );
+        }
+
+        Ok(Self {
+            vertices: Shape::buffer(context, BufferUsage::VERTEX_BUFFER, vertices)?,
+            indices: indices
+                .map(|i| Shape::buffer(context, BufferUsage::INDEX_BUFFER, i))
+                .transpose()?,
+            topology,
+            bounds: Bounds::from_points(vertices.iter().map(|v| Vector2::from(v.position))),
+        })
+    }
+
+    pub fn rect(
+        context: &Context,
+        dims: Vector2<f32>,
+        colors: [Vector4<f32>; 4],
+    ) -> anyhow::Result<Self> {
+        let dims = dims / 2.0;
+        let vertices = [
+            Vector2::new(-dims.x, -dims.y),
+            Vector2::new(dims.x, -dims.y),
+            Vector2::new(dims.x, dims.y),
+            Vector2::new(-dims.x, dims.y),
+        ]
+        .into_iter()
+        .zip(colors)
+        .map(|(p, c)| ColorVertex2::new(p, c))
+        .collect::<Vec<_>>();
+
+        Self::new(context, &vertices, None, PrimitiveTopology::TriangleFan)
+    }
+
+    pub fn radial(
+        context: &Context,
+        radius: f32,
+        segments: u32,
+        inner: Vector4<f32>,
+        outer: Vector4<f32>,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(segments >= 3, "Radial mesh needs at least 3 segments");
+
+        let vertices = std::iter::once(ColorVertex2::new(Vector2::zeros(), inner))
+            .chain((0..=segments).map(|i| {
+                let angle = std::f32::consts::TAU * i as f32 / segments as f32;
+
+                ColorVertex2::new(Vector2::new(angle.cos(), angle.sin()) * radius, outer)
+            }))
+            .collect::<Vec<_>>();
+
+        Self::new(context, &vertices, None, PrimitiveTopology::TriangleFan)
+    }
+
+    pub fn polygon(
+        context: &Context,
+        points: &[Vector2<f32>],
+        color: Vector4<f32>,
+    ) -> anyhow::Result<Self> {
+        let indices = triangulate(points)?;
+        let vertices = points
+            .iter()
+            .map(|&p| ColorVertex2::new(p, color))
+            .collect::<Vec<_>>();
+
+        Self::new(
+            context,
+            &vertices,
+            Some(&indices),
+            PrimitiveTopology::TriangleList,
+        )
+    }
+}
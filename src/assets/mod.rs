@@ -1,5 +1,29 @@
+pub mod animation;
+pub mod asset_server;
+pub mod bitmap_font;
+pub mod font;
+pub mod material;
+pub mod mesh;
+pub mod render_target;
+pub mod sampler_preset;
+pub mod shader;
 pub mod shape;
 pub mod texture;
+pub mod texture_atlas;
+pub mod tiled;
+pub mod upload_queue;
 
+pub use animation::Animation;
+pub use asset_server::{AssetEvent, AssetServer, Handle};
+pub use bitmap_font::BitmapFont;
+pub use font::Font;
+pub use material::Material;
+pub use mesh::Mesh;
+pub use render_target::RenderTarget;
+pub use sampler_preset::SamplerPreset;
+pub use shader::Shader;
 pub use shape::Shape;
 pub use texture::Texture;
+pub use texture_atlas::TextureAtlas;
+pub use tiled::TiledMap;
+pub use upload_queue::{Upload, UploadQueue};
@@ -0,0 +1,86 @@
+use super::Texture;
+use crate::Context;
+use nalgebra::Vector4;
+use std::sync::Arc;
+use vulkano::{
+    format::Format,
+    image::{sampler::Sampler, view::ImageView, Image, ImageCreateInfo, ImageType, ImageUsage},
+    memory::allocator::AllocationCreateInfo,
+    pipeline::graphics::viewport::Viewport,
+    render_pass::{Framebuffer, FramebufferCreateInfo},
+};
+
+#[derive(Clone)]
+pub struct RenderTarget {
+    pub color: Arc<ImageView>,
+    pub depth: Arc<ImageView>,
+    pub framebuffer: Arc<Framebuffer>,
+    pub bg: Vector4<f32>,
+}
+
+impl RenderTarget {
+    pub fn new(
+        context: &Context,
+        width: u32,
+        height: u32,
+        bg: Vector4<f32>,
+    ) -> anyhow::Result<Self> {
+        let color = ImageView::new_default(Image::new(
+            context.memory_allocator.clone(),
+            ImageCreateInfo {
+                image_type: ImageType::Dim2d,
+                format: Context::SCENE_FORMAT,
+                extent: [width, height, 1],
+                usage: ImageUsage::SAMPLED | ImageUsage::COLOR_ATTACHMENT,
+                ..Default::default()
+            },
+            AllocationCreateInfo::default(),
+        )?)?;
+        let depth = ImageView::new_default(Image::new(
+            context.memory_allocator.clone(),
+            ImageCreateInfo {
+                image_type: ImageType::Dim2d,
+                format: Format::D16_UNORM,
+                extent: [width, height, 1],
+                usage: ImageUsage::DEPTH_STENCIL_ATTACHMENT | ImageUsage::TRANSIENT_ATTACHMENT,
+                ..Default::default()
+            },
+            AllocationCreateInfo::default(),
+        )?)?;
+        let framebuffer = Framebuffer::new(
+            context.render_pass.clone(),
+            FramebufferCreateInfo {
+                attachments: vec![color.clone(), depth.clone()],
+                ..Default::default()
+            },
+        )?;
+
+        Ok(Self {
+            color,
+            depth,
+            framebuffer,
+            bg,
+        })
+    }
+
+    pub fn extent(&self) -> [u32; 2] {
+        self.framebuffer.extent()
+    }
+
+    pub fn viewport(&self) -> Viewport {
+        let extent = self.extent();
+
+        Viewport {
+            offset: [0.0, 0.0],
+            extent: [extent[0] as f32, extent[1] as f32],
+            depth_range: 0.0..=1.0,
+        }
+    }
+
+    pub fn texture(&self, sampler: Arc<Sampler>) -> Texture {
+        Texture {
+            image: self.color.clone(),
+            sampler,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use vulkano::{
+    device::Device,
+    image::sampler::{
+        Filter, Sampler, SamplerAddressMode, SamplerCreateInfo, SamplerMipmapMode, LOD_CLAMP_NONE,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Filtering {
+    #[default]
+    Nearest,
+    Linear,
+    LinearMipmapped,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Wrap {
+    Repeat,
+    #[default]
+    Clamp,
+    Mirror,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SamplerPreset {
+    pub filtering: Filtering,
+    pub wrap: Wrap,
+}
+
+impl SamplerPreset {
+    pub const PIXEL_ART: Self = Self::new(Filtering::Nearest, Wrap::Clamp);
+    pub const LINEAR: Self = Self::new(Filtering::Linear, Wrap::Clamp);
+    pub const LINEAR_MIPMAPPED: Self = Self::new(Filtering::LinearMipmapped, Wrap::Clamp);
+
+    pub const fn new(filtering: Filtering, wrap: Wrap) -> Self {
+        Self { filtering, wrap }
+    }
+
+    pub const fn with_wrap(self, wrap: Wrap) -> Self {
+        Self::new(self.filtering, wrap)
+    }
+
+    pub fn create(&self, device: Arc<Device>) -> anyhow::Result<Arc<Sampler>> {
+        let (filter, mipmap_mode, lod) = match self.filtering {
+            Filtering::Nearest => (Filter::Nearest, SamplerMipmapMode::Nearest, 0.0..=0.0),
+            Filtering::Linear => (Filter::Linear, SamplerMipmapMode::Nearest, 0.0..=0.0),
+            Filtering::LinearMipmapped => (
+                Filter::Linear,
+                SamplerMipmapMode::Linear,
+                0.0..=LOD_CLAMP_NONE,
+            ),
+        };
+        let address_mode = match self.wrap {
+            Wrap::Repeat => SamplerAddressMode::Repeat,
+            Wrap::Clamp => SamplerAddressMode::ClampToEdge,
+            Wrap::Mirror => SamplerAddressMode::MirroredRepeat,
+        };
+
+        Ok(Sampler::new(
+            device,
+            SamplerCreateInfo {
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_mode,
+                address_mode: [address_mode; 3],
+                lod,
+                ..Default::default()
+            },
+        )?)
+    }
+}
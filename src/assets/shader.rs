@@ -0,0 +1,65 @@
+use crate::Context;
+use anyhow::Context as _;
+use shaderc::{CompileOptions, Compiler, ShaderKind};
+use std::path::Path;
+use vulkano::shader::{EntryPoint, ShaderModule, ShaderModuleCreateInfo};
+
+#[derive(Clone)]
+pub struct Shader {
+    pub entry_point: EntryPoint,
+}
+
+impl Shader {
+    pub fn compile(
+        context: &Context,
+        source: &str,
+        kind: ShaderKind,
+        name: &str,
+    ) -> anyhow::Result<Self> {
+        Self::from_spirv(context, &Self::spirv(source, kind, name)?, name)
+    }
+
+    pub fn from_spirv(context: &Context, spirv: &[u32], name: &str) -> anyhow::Result<Self> {
+        let module = unsafe {
+            ShaderModule::new(context.device.clone(), ShaderModuleCreateInfo::new(spirv))?
+        };
+
+        Ok(Self {
+            entry_point: module
+                .entry_point("main")
+                .ok_or_else(|| anyhow::anyhow!("Shader {name} has no main entry point"))?,
+        })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(context: &Context, path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+
+        Self::from_spirv(context, &Self::decode(path)?, &path.display().to_string())
+    }
+
+    pub fn spirv(source: &str, kind: ShaderKind, name: &str) -> anyhow::Result<Vec<u32>> {
+        let compiler =
+            Compiler::new().ok_or_else(|| anyhow::anyhow!("Failed to create shader compiler"))?;
+        let options = CompileOptions::new()
+            .ok_or_else(|| anyhow::anyhow!("Failed to create shader compile options"))?;
+        let spirv = compiler
+            .compile_into_spirv(source, kind, name, "main", Some(&options))
+            .with_context(|| format!("Failed to compile shader {name}"))?;
+
+        Ok(spirv.as_binary().to_vec())
+    }
+
+    pub fn decode<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<u32>> {
+        let path = path.as_ref();
+        let kind = match path.extension().and_then(|e| e.to_str()) {
+            Some("vert") => ShaderKind::Vertex,
+            Some("frag") => ShaderKind::Fragment,
+            Some("comp") => ShaderKind::Compute,
+            _ => ShaderKind::InferFromSource,
+        };
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read shader {}", path.display()))?;
+
+        Self::spirv(&source, kind, &path.display().to_string())
+    }
+}
@@ -0,0 +1,50 @@
+use nalgebra::{Matrix3, Vector2};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub min: Vector2<f32>,
+    pub max: Vector2<f32>,
+}
+
+impl Bounds {
+    pub fn new(min: Vector2<f32>, max: Vector2<f32>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn from_points<I: IntoIterator<Item = Vector2<f32>>>(points: I) -> Self {
+        points.into_iter().fold(
+            Self::new(
+                Vector2::repeat(f32::INFINITY),
+                Vector2::repeat(f32::NEG_INFINITY),
+            ),
+            |b, p| Self::new(b.min.inf(&p), b.max.sup(&p)),
+        )
+    }
+
+    pub fn corners(&self) -> [Vector2<f32>; 4] {
+        [
+            self.min,
+            Vector2::new(self.max.x, self.min.y),
+            self.max,
+            Vector2::new(self.min.x, self.max.y),
+        ]
+    }
+
+    pub fn transform(&self, matrix: &Matrix3<f32>) -> Self {
+        Self::from_points(self.corners().map(|c| (matrix * c.push(1.0)).xy()))
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    pub fn contains(&self, point: Vector2<f32>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+}
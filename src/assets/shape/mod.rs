@@ -1,36 +1,105 @@
+pub mod bounds;
+pub mod triangulate;
 pub mod vertex2;
 
+pub use bounds::Bounds;
+pub use triangulate::triangulate;
 pub use vertex2::Vertex2;
 
+use super::Upload;
 use crate::Context;
 use nalgebra::Vector2;
+use std::f32::consts::{FRAC_PI_2, TAU};
 use vulkano::{
-    buffer::{subbuffer::Subbuffer, Buffer, BufferCreateInfo, BufferUsage},
+    buffer::{subbuffer::Subbuffer, Buffer, BufferContents, BufferCreateInfo, BufferUsage},
+    command_buffer::CopyBufferInfo,
     memory::allocator::{AllocationCreateInfo, MemoryTypeFilter},
+    pipeline::graphics::input_assembly::PrimitiveTopology,
+    DeviceSize,
 };
 
 #[derive(Clone)]
 pub struct Shape {
     pub vertices: Subbuffer<[Vertex2]>,
+    pub indices: Option<Subbuffer<[u32]>>,
+    pub topology: PrimitiveTopology,
+    pub bounds: Bounds,
 }
 
 impl Shape {
     pub fn new(context: &Context, vertices: &[Vertex2]) -> anyhow::Result<Self> {
+        Self::with_topology(context, vertices, PrimitiveTopology::TriangleFan)
+    }
+
+    pub fn with_topology(
+        context: &Context,
+        vertices: &[Vertex2],
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            vertices: Self::buffer(context, BufferUsage::VERTEX_BUFFER, vertices)?,
+            indices: None,
+            topology,
+            bounds: Bounds::from_points(vertices.iter().map(|v| Vector2::from(v.position))),
+        })
+    }
+
+    pub fn indexed(
+        context: &Context,
+        vertices: &[Vertex2],
+        indices: &[u32],
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            indices.iter().all(|&i| (i as usize) < vertices.len()),
+            "Shape index out of range for {} vertices",
+            vertices.len()
+        );
+
         Ok(Self {
-            vertices: Buffer::from_iter(
+            indices: Some(Self::buffer(context, BufferUsage::INDEX_BUFFER, indices)?),
+            ..Self::with_topology(context, vertices, topology)?
+        })
+    }
+
+    pub fn upload(context: &Context, vertices: &[Vertex2]) -> anyhow::Result<Upload<Self>> {
+        let staging = Buffer::from_iter(
+            context.memory_allocator.clone(),
+            BufferCreateInfo {
+                usage: BufferUsage::TRANSFER_SRC,
+                ..Default::default()
+            },
+            AllocationCreateInfo {
+                memory_type_filter: MemoryTypeFilter::PREFER_HOST
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+            vertices.iter().cloned(),
+        )?;
+        let shape = Self {
+            vertices: Buffer::new_slice(
                 context.memory_allocator.clone(),
                 BufferCreateInfo {
-                    usage: BufferUsage::VERTEX_BUFFER,
+                    usage: BufferUsage::VERTEX_BUFFER | BufferUsage::TRANSFER_DST,
                     ..Default::default()
                 },
                 AllocationCreateInfo {
-                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
-                        | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
                     ..Default::default()
                 },
-                vertices.iter().cloned(),
+                vertices.len() as DeviceSize,
             )?,
-        })
+            indices: None,
+            topology: PrimitiveTopology::TriangleFan,
+            bounds: Bounds::from_points(vertices.iter().map(|v| Vector2::from(v.position))),
+        };
+        let dst = shape.vertices.clone();
+
+        Ok(context.uploads.lock().push(shape, move |builder| {
+            builder.copy_buffer(CopyBufferInfo::buffers(staging.clone(), dst.clone()))?;
+
+            Ok(())
+        }))
     }
 
     pub fn rect(context: &Context, dims: Vector2<f32>) -> anyhow::Result<Self> {
@@ -47,4 +116,166 @@ impl Shape {
 
         Self::new(context, &vertices)
     }
+
+    pub fn circle(context: &Context, radius: f32, segments: u32) -> anyhow::Result<Self> {
+        Self::regular_polygon(context, radius, segments)
+    }
+
+    pub fn regular_polygon(context: &Context, radius: f32, sides: u32) -> anyhow::Result<Self> {
+        anyhow::ensure!(sides >= 3, "Regular polygon needs at least 3 sides");
+
+        let outline: Vec<_> = (0..sides)
+            .map(|i| {
+                let angle = TAU * i as f32 / sides as f32 - FRAC_PI_2;
+
+                Vector2::new(angle.cos(), angle.sin()) * radius
+            })
+            .collect();
+
+        Self::fan(context, &outline)
+    }
+
+    pub fn rounded_rect(
+        context: &Context,
+        dims: Vector2<f32>,
+        radius: f32,
+        segments: u32,
+    ) -> anyhow::Result<Self> {
+        let half = dims / 2.0;
+        let radius = radius.clamp(0.0, half.min());
+        let segments = segments.max(1);
+        let outline: Vec<_> = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)]
+            .into_iter()
+            .enumerate()
+            .flat_map(|(corner, (x, y))| {
+                let center = Vector2::new(x * (half.x - radius), y * (half.y - radius));
+
+                (0..=segments).map(move |i| {
+                    let angle = FRAC_PI_2 * (corner as f32 + i as f32 / segments as f32);
+
+                    center + Vector2::new(angle.cos(), angle.sin()) * radius
+                })
+            })
+            .collect();
+
+        Self::fan(context, &outline)
+    }
+
+    pub fn line(
+        context: &Context,
+        points: &[Vector2<f32>],
+        thickness: f32,
+    ) -> anyhow::Result<Self> {
+        let mut points = points.to_vec();
+
+        points.dedup();
+
+        anyhow::ensure!(points.len() >= 2, "Line needs at least 2 distinct points");
+
+        let half = thickness / 2.0;
+        let normal = |a: Vector2<f32>, b: Vector2<f32>| {
+            let d = (b - a).normalize();
+
+            Vector2::new(-d.y, d.x)
+        };
+        let length: f32 = points.windows(2).map(|w| (w[1] - w[0]).norm()).sum();
+        let mut distance = 0.0;
+        let mut vertices = Vec::with_capacity(points.len() * 2);
+
+        for (i, &p) in points.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|j| normal(points[j], p));
+            let next = points.get(i + 1).map(|&n| normal(p, n));
+            let offset = match (prev, next) {
+                (Some(a), Some(b)) => {
+                    let miter = (a + b).try_normalize(f32::EPSILON).unwrap_or(b);
+
+                    miter * (half / miter.dot(&b).max(0.25))
+                }
+                (Some(n), None) | (None, Some(n)) => n * half,
+                (None, None) => unreachable!(),
+            };
+
+            if let Some(j) = i.checked_sub(1) {
+                distance += (p - points[j]).norm();
+            }
+
+            let u = distance / length;
+
+            vertices.push(Vertex2::new(p + offset, Vector2::new(u, 0.0)));
+            vertices.push(Vertex2::new(p - offset, Vector2::new(u, 1.0)));
+        }
+
+        let indices: Vec<_> = (0..points.len() as u32 - 1)
+            .flat_map(|i| {
+                let i = i * 2;
+
+                [i, i + 1, i + 2, i + 1, i + 3, i + 2]
+            })
+            .collect();
+
+        Self::indexed(
+            context,
+            &vertices,
+            &indices,
+            PrimitiveTopology::TriangleList,
+        )
+    }
+
+    pub fn polygon(context: &Context, points: &[Vector2<f32>]) -> anyhow::Result<Self> {
+        let indices = triangulate(points)?;
+
+        Self::indexed(
+            context,
+            &Self::outline_vertices(points),
+            &indices,
+            PrimitiveTopology::TriangleList,
+        )
+    }
+
+    fn fan(context: &Context, outline: &[Vector2<f32>]) -> anyhow::Result<Self> {
+        let bounds = Bounds::from_points(outline.iter().cloned());
+        let center = (bounds.min + bounds.max) / 2.0;
+        let vertices = Self::outline_vertices(&[&[center], outline].concat());
+        let len = outline.len() as u32;
+        let indices: Vec<_> = (0..len)
+            .flat_map(|i| [0, i + 1, (i + 1) % len + 1])
+            .collect();
+
+        Self::indexed(
+            context,
+            &vertices,
+            &indices,
+            PrimitiveTopology::TriangleList,
+        )
+    }
+
+    fn outline_vertices(points: &[Vector2<f32>]) -> Vec<Vertex2> {
+        let bounds = Bounds::from_points(points.iter().cloned());
+        let size = (bounds.max - bounds.min).map(|s| s.max(f32::EPSILON));
+
+        points
+            .iter()
+            .map(|&p| Vertex2::new(p, (p - bounds.min).component_div(&size)))
+            .collect()
+    }
+
+    pub(crate) fn buffer<T: BufferContents + Copy>(
+        context: &Context,
+        usage: BufferUsage,
+        data: &[T],
+    ) -> anyhow::Result<Subbuffer<[T]>> {
+        Ok(Buffer::from_iter(
+            context.memory_allocator.clone(),
+            BufferCreateInfo {
+                usage,
+                ..Default::default()
+            },
+            AllocationCreateInfo {
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+            data.iter().cloned(),
+        )?)
+    }
 }
@@ -0,0 +1,75 @@
+use anyhow::Context as _;
+use nalgebra::Vector2;
+
+pub fn triangulate(points: &[Vector2<f32>]) -> anyhow::Result<Vec<u32>> {
+    anyhow::ensure!(points.len() >= 3, "Polygon needs at least 3 points");
+
+    let winding = area(points).signum();
+    let cross = |a: usize, b: usize, c: usize| {
+        (points[b] - points[a]).perp(&(points[c] - points[b])) * winding
+    };
+    let contains = |a: usize, b: usize, c: usize, p: usize| {
+        cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+    };
+    let epsilon = f32::EPSILON
+        * points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| (b - a).norm_squared())
+            .fold(0.0, f32::max);
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut indices = Vec::with_capacity((points.len() - 2) * 3);
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let corner = |i: usize| {
+            (
+                remaining[(i + len - 1) % len],
+                remaining[i],
+                remaining[(i + 1) % len],
+            )
+        };
+        let ear = (0..len).find(|&i| {
+            let (a, b, c) = corner(i);
+
+            cross(a, b, c) > 0.0
+                && !remaining
+                    .iter()
+                    .any(|&p| p != a && p != b && p != c && contains(a, b, c, p))
+        });
+
+        match ear {
+            Some(i) => {
+                let (a, b, c) = corner(i);
+
+                indices.extend([a, b, c].map(|i| i as u32));
+
+                remaining.remove(i);
+            }
+            None => {
+                let i = (0..len)
+                    .find(|&i| {
+                        let (a, b, c) = corner(i);
+
+                        cross(a, b, c).abs() <= epsilon
+                    })
+                    .context("Polygon is self-intersecting")?;
+
+                remaining.remove(i);
+            }
+        }
+    }
+
+    indices.extend(remaining.iter().map(|&i| i as u32));
+
+    Ok(indices)
+}
+
+fn area(points: &[Vector2<f32>]) -> f32 {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp(b))
+        .sum::<f32>()
+        / 2.0
+}
@@ -1,14 +1,59 @@
+use super::{upload_queue::UploadBuilder, Upload};
 use crate::Context;
-use std::sync::Arc;
+use anyhow::Context as _;
+use image::RgbaImage;
+use std::{path::Path, sync::Arc};
 use vulkano::{
-    buffer::{Buffer, BufferCreateInfo, BufferUsage},
-    command_buffer::{AutoCommandBufferBuilder, CommandBufferUsage, CopyBufferToImageInfo},
+    buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer},
+    command_buffer::{
+        AutoCommandBufferBuilder, BlitImageInfo, CommandBufferUsage, CopyBufferToImageInfo,
+        ImageBlit,
+    },
     format::Format,
-    image::{sampler::Sampler, view::ImageView, Image, ImageCreateInfo, ImageType, ImageUsage},
+    image::{
+        sampler::{Filter, Sampler},
+        view::ImageView,
+        Image, ImageCreateInfo, ImageSubresourceLayers, ImageType, ImageUsage,
+    },
     memory::allocator::{AllocationCreateInfo, MemoryTypeFilter},
     sync::{self, GpuFuture},
 };
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TextureOptions {
+    pub srgb: bool,
+    pub premultiply: bool,
+    pub mipmaps: bool,
+}
+
+impl Default for TextureOptions {
+    fn default() -> Self {
+        Self {
+            srgb: true,
+            premultiply: false,
+            mipmaps: false,
+        }
+    }
+}
+
+impl TextureOptions {
+    pub fn format(&self) -> Format {
+        if self.srgb {
+            Format::R8G8B8A8_SRGB
+        } else {
+            Format::R8G8B8A8_UNORM
+        }
+    }
+
+    pub fn mip_levels(&self, width: u32, height: u32) -> u32 {
+        if self.mipmaps {
+            u32::BITS - width.max(height).max(1).leading_zeros()
+        } else {
+            1
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Texture {
     pub image: Arc<ImageView>,
@@ -23,11 +68,173 @@ impl Texture {
         width: u32,
         height: u32,
     ) -> anyhow::Result<Self> {
+        Self::with_options(
+            context,
+            sampler,
+            source,
+            width,
+            height,
+            TextureOptions::default(),
+        )
+    }
+
+    pub fn from_file<P: AsRef<Path>>(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        path: P,
+        options: TextureOptions,
+    ) -> anyhow::Result<Self> {
+        let image = Self::decode(path)?;
+
+        Self::with_options(
+            context,
+            sampler,
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            options,
+        )
+    }
+
+    pub fn from_memory(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        source: &[u8],
+        options: TextureOptions,
+    ) -> anyhow::Result<Self> {
+        let image = image::load_from_memory(source)
+            .context("Failed to decode texture from memory")?
+            .into_rgba8();
+
+        Self::with_options(
+            context,
+            sampler,
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            options,
+        )
+    }
+
+    pub fn with_options(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        source: &[u8],
+        width: u32,
+        height: u32,
+        options: TextureOptions,
+    ) -> anyhow::Result<Self> {
+        let (buffer, image) = Self::create(context, source, width, height, options)?;
         let mut upload = AutoCommandBufferBuilder::primary(
             &context.command_buffer_allocator,
             context.queue.queue_family_index(),
             CommandBufferUsage::OneTimeSubmit,
         )?;
+
+        Self::record(&mut upload, buffer, image.clone())?;
+
+        let command_buffer = upload.build()?;
+        let future = sync::now(context.device.clone())
+            .then_execute(context.queue.clone(), command_buffer)?
+            .then_signal_fence_and_flush()?;
+
+        future.wait(None)?;
+
+        Ok(Self {
+            image: ImageView::new_default(image)?,
+            sampler,
+        })
+    }
+
+    pub fn upload(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        source: &[u8],
+        width: u32,
+        height: u32,
+        options: TextureOptions,
+    ) -> anyhow::Result<Upload<Self>> {
+        let (buffer, image) = Self::create(context, source, width, height, options)?;
+        let texture = Self {
+            image: ImageView::new_default(image.clone())?,
+            sampler,
+        };
+
+        Ok(context.uploads.lock().push(texture, move |builder| {
+            Self::record(builder, buffer.clone(), image.clone())
+        }))
+    }
+
+    pub fn update(
+        &self,
+        context: &Context,
+        source: &[u8],
+        width: u32,
+        height: u32,
+        options: TextureOptions,
+    ) -> anyhow::Result<Upload<()>> {
+        let image = self.image.image().clone();
+
+        anyhow::ensure!(
+            image.extent() == [width, height, 1] && image.format() == options.format(),
+            "Texture update must keep the original extent and format"
+        );
+
+        let buffer = Self::staging(context, source, width, height, options)?;
+
+        Ok(context.uploads.lock().push((), move |builder| {
+            Self::record(builder, buffer.clone(), image.clone())
+        }))
+    }
+
+    pub fn decode<P: AsRef<Path>>(path: P) -> anyhow::Result<RgbaImage> {
+        let path = path.as_ref();
+
+        Ok(image::ImageReader::open(path)
+            .with_context(|| format!("Failed to open texture {}", path.display()))?
+            .with_guessed_format()?
+            .decode()
+            .with_context(|| format!("Failed to decode texture {}", path.display()))?
+            .into_rgba8())
+    }
+
+    fn create(
+        context: &Context,
+        source: &[u8],
+        width: u32,
+        height: u32,
+        options: TextureOptions,
+    ) -> anyhow::Result<(Subbuffer<[u8]>, Arc<Image>)> {
+        let buffer = Self::staging(context, source, width, height, options)?;
+        let image = Image::new(
+            context.memory_allocator.clone(),
+            ImageCreateInfo {
+                image_type: ImageType::Dim2d,
+                format: options.format(),
+                extent: [width, height, 1],
+                mip_levels: options.mip_levels(width, height),
+                usage: ImageUsage::TRANSFER_SRC | ImageUsage::TRANSFER_DST | ImageUsage::SAMPLED,
+                ..Default::default()
+            },
+            AllocationCreateInfo::default(),
+        )?;
+
+        Ok((buffer, image))
+    }
+
+    fn staging(
+        context: &Context,
+        source: &[u8],
+        width: u32,
+        height: u32,
+        options: TextureOptions,
+    ) -> anyhow::Result<Subbuffer<[u8]>> {
+        anyhow::ensure!(
+            source.len() == width as usize * height as usize * 4,
+            "Texture source is {} bytes, expected {width}x{height} RGBA",
+            source.len()
+        );
+
         let buffer = Buffer::from_iter(
             context.memory_allocator.clone(),
             BufferCreateInfo {
@@ -41,30 +248,88 @@ impl Texture {
             },
             source.iter().cloned(),
         )?;
-        let image = Image::new(
-            context.memory_allocator.clone(),
-            ImageCreateInfo {
-                image_type: ImageType::Dim2d,
-                format: Format::R8G8B8A8_SRGB,
-                extent: [width, height, 1],
-                usage: ImageUsage::TRANSFER_DST | ImageUsage::SAMPLED,
-                ..Default::default()
-            },
-            AllocationCreateInfo::default(),
-        )?;
 
-        upload.copy_buffer_to_image(CopyBufferToImageInfo::buffer_image(buffer, image.clone()))?;
+        if options.premultiply {
+            Self::premultiply(&mut buffer.write()?, options.srgb);
+        }
 
-        let command_buffer = upload.build()?;
-        let future = sync::now(context.device.clone())
-            .then_execute(context.queue.clone(), command_buffer)?
-            .then_signal_fence_and_flush()?;
+        Ok(buffer)
+    }
 
-        future.wait(None)?;
+    fn record(
+        builder: &mut UploadBuilder,
+        buffer: Subbuffer<[u8]>,
+        image: Arc<Image>,
+    ) -> anyhow::Result<()> {
+        let [width, height, _] = image.extent();
 
-        Ok(Self {
-            image: ImageView::new_default(image)?,
-            sampler,
-        })
+        builder.copy_buffer_to_image(CopyBufferToImageInfo::buffer_image(buffer, image.clone()))?;
+
+        for level in 1..image.mip_levels() {
+            let extent = |level: u32| [(width >> level).max(1), (height >> level).max(1), 1];
+
+            builder.blit_image(BlitImageInfo {
+                regions: [ImageBlit {
+                    src_subresource: ImageSubresourceLayers {
+                        mip_level: level - 1,
+                        ..image.subresource_layers()
+                    },
+                    src_offsets: [[0; 3], extent(level - 1)],
+                    dst_subresource: ImageSubresourceLayers {
+                        mip_level: level,
+                        ..image.subresource_layers()
+                    },
+                    dst_offsets: [[0; 3], extent(level)],
+                    ..Default::default()
+                }]
+                .into(),
+                filter: Filter::Linear,
+                ..BlitImageInfo::images(image.clone(), image.clone())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn premultiply(pixels: &mut [u8], srgb: bool) {
+        if !srgb {
+            for p in pixels.chunks_exact_mut(4) {
+                let a = p[3] as u32;
+
+                for c in &mut p[..3] {
+                    *c = ((*c as u32 * a + 127) / 255) as u8;
+                }
+            }
+
+            return;
+        }
+
+        let linear: Vec<f32> = (0..=255u8)
+            .map(|c| Self::to_linear(c as f32 / 255.0))
+            .collect();
+
+        for p in pixels.chunks_exact_mut(4) {
+            let a = p[3] as f32 / 255.0;
+
+            for c in &mut p[..3] {
+                *c = (Self::to_srgb(linear[*c as usize] * a) * 255.0).round() as u8;
+            }
+        }
+    }
+
+    fn to_linear(c: f32) -> f32 {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    fn to_srgb(c: f32) -> f32 {
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
     }
 }
@@ -0,0 +1,62 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Descriptor {
+    pub frames: Frames,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Frames {
+    Hash(IndexMap<String, Frame>),
+    Array(Vec<NamedFrame>),
+}
+
+#[derive(Deserialize)]
+pub struct NamedFrame {
+    pub filename: String,
+    #[serde(flatten)]
+    pub frame: Frame,
+}
+
+#[derive(Deserialize)]
+pub struct Frame {
+    pub frame: Rect,
+    #[serde(default)]
+    pub duration: Option<u32>,
+    #[serde(default)]
+    pub rotated: bool,
+    #[serde(default)]
+    pub trimmed: bool,
+    #[serde(default, rename = "spriteSourceSize")]
+    pub sprite_source_size: Option<Rect>,
+    #[serde(default, rename = "sourceSize")]
+    pub source_size: Option<Size>,
+}
+
+#[derive(Deserialize)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub w: u32,
+    pub h: u32,
+}
+
+#[derive(Deserialize)]
+pub struct Size {
+    pub w: u32,
+    pub h: u32,
+}
+
+impl Descriptor {
+    pub fn frames(self) -> Vec<(String, Frame)> {
+        match self.frames {
+            Frames::Hash(frames) => frames.into_iter().collect(),
+            Frames::Array(frames) => frames
+                .into_iter()
+                .map(|NamedFrame { filename, frame }| (filename, frame))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+pub mod descriptor;
+pub mod region;
+
+pub use descriptor::Descriptor;
+pub use region::Region;
+
+use super::Texture;
+use nalgebra::Vector2;
+use std::{collections::HashMap, path::Path, time::Duration};
+
+#[derive(Clone)]
+pub struct TextureAtlas {
+    pub texture: Texture,
+    pub regions: Vec<Region>,
+    pub durations: Vec<Option<Duration>>,
+    pub names: HashMap<String, usize>,
+}
+
+impl TextureAtlas {
+    pub fn new(texture: Texture, regions: Vec<Region>) -> Self {
+        Self {
+            texture,
+            durations: vec![None; regions.len()],
+            regions,
+            names: HashMap::new(),
+        }
+    }
+
+    pub fn grid(
+        texture: Texture,
+        tile: Vector2<u32>,
+        columns: u32,
+        rows: u32,
+        padding: Vector2<u32>,
+        offset: Vector2<u32>,
+    ) -> Self {
+        let dimensions = Self::dimensions(&texture);
+        let regions = (0..rows)
+            .flat_map(|y| (0..columns).map(move |x| Vector2::new(x, y)))
+            .map(|p| {
+                Region::from_pixels(
+                    offset + p.component_mul(&(tile + padding)),
+                    tile,
+                    dimensions,
+                )
+            })
+            .collect();
+
+        Self::new(texture, regions)
+    }
+
+    pub fn from_rects<I, S>(texture: Texture, rects: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Vector2<u32>, Vector2<u32>)>,
+        S: Into<String>,
+    {
+        let dimensions = Self::dimensions(&texture);
+        let mut atlas = Self::new(texture, Vec::new());
+
+        for (name, position, size) in rects {
+            atlas.push(name, Region::from_pixels(position, size, dimensions), None);
+        }
+
+        atlas
+    }
+
+    pub fn from_json(texture: Texture, source: &str) -> anyhow::Result<Self> {
+        Self::from_descriptor(texture, serde_json::from_str(source)?)
+    }
+
+    pub fn from_toml(texture: Texture, source: &str) -> anyhow::Result<Self> {
+        Self::from_descriptor(texture, toml::from_str(source)?)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(texture: Texture, path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json(texture, &source),
+            Some("toml") => Self::from_toml(texture, &source),
+            _ => Err(anyhow::anyhow!(
+                "Unsupported texture atlas descriptor: {}",
+                path.display()
+            )),
+        }
+    }
+
+    pub fn from_descriptor(texture: Texture, descriptor: Descriptor) -> anyhow::Result<Self> {
+        let dimensions = Self::dimensions(&texture);
+        let mut atlas = Self::new(texture, Vec::new());
+
+        for (name, frame) in descriptor.frames() {
+            let r = frame.frame;
+            let mut region = if frame.rotated {
+                Region::from_pixels(Vector2::new(r.x, r.y), Vector2::new(r.h, r.w), dimensions)
+                    .rotated()
+            } else {
+                Region::from_pixels(Vector2::new(r.x, r.y), Vector2::new(r.w, r.h), dimensions)
+            };
+
+            if frame.trimmed {
+                let pixels = dimensions.cast::<f32>();
+                let offset = frame
+                    .sprite_source_size
+                    .map_or(Vector2::zeros(), |s| Vector2::new(s.x, s.y));
+                let source = frame
+                    .source_size
+                    .map_or(Vector2::new(r.w, r.h), |s| Vector2::new(s.w, s.h));
+
+                region = region.trimmed(
+                    offset.cast::<f32>().component_div(&pixels),
+                    source.cast::<f32>().component_div(&pixels),
+                );
+            }
+
+            atlas.push(
+                name,
+                region,
+                frame.duration.map(|d| Duration::from_millis(d as u64)),
+            );
+        }
+
+        Ok(atlas)
+    }
+
+    pub fn push<S: Into<String>>(
+        &mut self,
+        name: S,
+        region: Region,
+        duration: Option<Duration>,
+    ) -> usize {
+        let index = self.regions.len();
+
+        self.regions.push(region);
+        self.durations.push(duration);
+        self.names.insert(name.into(), index);
+
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn frame(&self, index: usize) -> Option<Region> {
+        self.regions.get(index).cloned()
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.names.get(name).cloned()
+    }
+
+    fn dimensions(texture: &Texture) -> Vector2<u32> {
+        let [width, height, _] = texture.image.image().extent();
+
+        Vector2::new(width, height)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::assets::shape::Bounds;
+use nalgebra::{Matrix3, Vector2, Vector4};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+    pub offset: Vector2<f32>,
+    pub size: Vector2<f32>,
+    pub trim_offset: Vector2<f32>,
+    pub source_size: Vector2<f32>,
+    pub rotated: bool,
+}
+
+impl Default for Region {
+    fn default() -> Self {
+        Self::new(Vector2::zeros(), Vector2::new(1.0, 1.0))
+    }
+}
+
+impl Region {
+    pub fn new(offset: Vector2<f32>, size: Vector2<f32>) -> Self {
+        Self {
+            offset,
+            size,
+            trim_offset: Vector2::zeros(),
+            source_size: size.abs(),
+            rotated: false,
+        }
+    }
+
+    pub fn from_pixels(
+        position: Vector2<u32>,
+        size: Vector2<u32>,
+        dimensions: Vector2<u32>,
+    ) -> Self {
+        let dimensions = dimensions.cast::<f32>();
+
+        Self::new(
+            position.cast::<f32>().component_div(&dimensions),
+            size.cast::<f32>().component_div(&dimensions),
+        )
+    }
+
+    pub fn rotated(mut self) -> Self {
+        self.rotated = true;
+        self.source_size = self.source_size.yx();
+        self
+    }
+
+    pub fn trimmed(mut self, trim_offset: Vector2<f32>, source_size: Vector2<f32>) -> Self {
+        self.trim_offset = trim_offset;
+        self.source_size = source_size;
+        self
+    }
+
+    pub fn frame_size(&self) -> Vector2<f32> {
+        if self.rotated {
+            self.size.yx()
+        } else {
+            self.size
+        }
+    }
+
+    pub fn is_trimmed(&self) -> bool {
+        self.trim_offset != Vector2::zeros() || self.frame_size().abs() != self.source_size
+    }
+
+    pub fn uv(&self, c: Vector2<f32>) -> Vector2<f32> {
+        let c = if self.rotated {
+            Vector2::new(1.0 - c.y, c.x)
+        } else {
+            c
+        };
+
+        self.offset + self.size.component_mul(&c)
+    }
+
+    pub fn trim(&self, bounds: &Bounds) -> Matrix3<f32> {
+        if !self.is_trimmed() {
+            return Matrix3::identity();
+        }
+
+        let frame = self.frame_size();
+        let scale = frame.abs().component_div(&self.source_size);
+        let mut offset = self.trim_offset.component_div(&self.source_size);
+
+        for i in 0..2 {
+            if frame[i] < 0.0 {
+                offset[i] = 1.0 - offset[i] - scale[i];
+            }
+        }
+
+        let extent = bounds.max - bounds.min;
+        let translation = bounds.min.component_mul(&(Vector2::repeat(1.0) - scale))
+            + offset.component_mul(&extent);
+
+        Matrix3::new_translation(&translation) * Matrix3::new_nonuniform_scaling(&scale)
+    }
+
+    pub fn to_vector(&self) -> Vector4<f32> {
+        Vector4::new(self.offset.x, self.offset.y, self.size.x, self.size.y)
+    }
+}
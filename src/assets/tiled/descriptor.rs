@@ -0,0 +1,250 @@
+use super::{tmj, tmx};
+use crate::components::{
+    properties::Property,
+    tilemap::{StaggerAxis, StaggerIndex, TileLayout},
+};
+use anyhow::Context as _;
+use base64::Engine;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use nalgebra::Vector2;
+use std::{
+    collections::HashMap,
+    io::Read,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct TiledTile {
+    pub class: String,
+    pub properties: HashMap<String, Property>,
+    pub animation: Vec<(u32, Duration)>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TiledTileset {
+    pub first_gid: u32,
+    pub source: Option<String>,
+    pub name: String,
+    pub tile_size: Vector2<u32>,
+    pub columns: u32,
+    pub tile_count: u32,
+    pub margin: u32,
+    pub spacing: u32,
+    pub image: Option<PathBuf>,
+    pub tiles: HashMap<u32, TiledTile>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TiledObject {
+    pub id: u32,
+    pub name: String,
+    pub class: String,
+    pub position: Vector2<f32>,
+    pub size: Vector2<f32>,
+    pub rotation: f32,
+    pub gid: Option<u32>,
+    pub visible: bool,
+    pub properties: HashMap<String, Property>,
+}
+
+#[derive(Clone, Debug)]
+pub enum TiledLayerData {
+    Tiles(Vec<u32>),
+    Objects(Vec<TiledObject>),
+}
+
+#[derive(Clone, Debug)]
+pub struct TiledLayer {
+    pub name: String,
+    pub class: String,
+    pub visible: bool,
+    pub opacity: f32,
+    pub offset: Vector2<f32>,
+    pub properties: HashMap<String, Property>,
+    pub data: TiledLayerData,
+}
+
+#[derive(Clone, Debug)]
+pub struct TiledDescriptor {
+    pub size: Vector2<u32>,
+    pub tile_size: Vector2<u32>,
+    pub layout: TileLayout,
+    pub tilesets: Vec<TiledTileset>,
+    pub layers: Vec<TiledLayer>,
+    pub properties: HashMap<String, Property>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Group {
+    pub offset: Vector2<f32>,
+    pub opacity: f32,
+    pub visible: bool,
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Self {
+            offset: Vector2::zeros(),
+            opacity: 1.0,
+            visible: true,
+        }
+    }
+}
+
+impl Group {
+    pub fn nest(&self, offset: Vector2<f32>, opacity: f32, visible: bool) -> Self {
+        Self {
+            offset: self.offset + offset,
+            opacity: self.opacity * opacity,
+            visible: self.visible && visible,
+        }
+    }
+}
+
+impl TiledDescriptor {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        if text.trim_start().starts_with('<') {
+            tmx::parse_map(text)
+        } else {
+            tmj::parse_map(text)
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read Tiled map {}", path.display()))?;
+        let mut descriptor = Self::parse(&text)
+            .with_context(|| format!("Failed to parse Tiled map {}", path.display()))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+
+        for tileset in &mut descriptor.tilesets {
+            let dir = match tileset.source.clone() {
+                Some(source) => {
+                    let path = dir.join(source);
+                    let text = std::fs::read_to_string(&path).with_context(|| {
+                        format!("Failed to read Tiled tileset {}", path.display())
+                    })?;
+                    let first_gid = tileset.first_gid;
+
+                    *tileset = TiledTileset::parse(&text).with_context(|| {
+                        format!("Failed to parse Tiled tileset {}", path.display())
+                    })?;
+                    tileset.first_gid = first_gid;
+
+                    path.parent().unwrap_or(Path::new("")).to_path_buf()
+                }
+                None => dir.to_path_buf(),
+            };
+
+            tileset.image = tileset.image.take().map(|image| dir.join(image));
+        }
+
+        Ok(descriptor)
+    }
+}
+
+impl TiledTileset {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        if text.trim_start().starts_with('<') {
+            tmx::parse_tileset(text)
+        } else {
+            tmj::parse_tileset(text)
+        }
+    }
+}
+
+pub(crate) fn layout(
+    orientation: &str,
+    side_length: f32,
+    axis: Option<&str>,
+    index: Option<&str>,
+) -> anyhow::Result<TileLayout> {
+    let axis = match axis {
+        Some("x") => StaggerAxis::X,
+        _ => StaggerAxis::Y,
+    };
+    let index = match index {
+        Some("even") => StaggerIndex::Even,
+        _ => StaggerIndex::Odd,
+    };
+
+    Ok(match orientation {
+        "orthogonal" => TileLayout::Orthogonal,
+        "isometric" => TileLayout::Isometric,
+        "staggered" => TileLayout::Hexagonal {
+            side_length: 0.0,
+            axis,
+            index,
+        },
+        "hexagonal" => TileLayout::Hexagonal {
+            side_length,
+            axis,
+            index,
+        },
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unsupported Tiled orientation: {orientation}"
+            ))
+        }
+    })
+}
+
+pub(crate) fn property(kind: &str, value: &str) -> anyhow::Result<Property> {
+    Ok(match kind {
+        "bool" => Property::Bool(value == "true"),
+        "int" | "object" => Property::Int(value.parse().context("Invalid int property")?),
+        "float" => Property::Float(value.parse().context("Invalid float property")?),
+        _ => Property::String(value.to_string()),
+    })
+}
+
+pub(crate) fn decode(
+    data: &str,
+    encoding: &str,
+    compression: Option<&str>,
+) -> anyhow::Result<Vec<u32>> {
+    if encoding == "csv" {
+        return data
+            .split(',')
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.parse().context("Invalid tile in CSV layer data"))
+            .collect();
+    }
+
+    anyhow::ensure!(
+        encoding == "base64",
+        "Unsupported Tiled layer encoding: {encoding}"
+    );
+
+    let raw = base64::engine::general_purpose::STANDARD
+        .decode(data.trim())
+        .context("Invalid base64 layer data")?;
+    let bytes = match compression.filter(|c| !c.is_empty()) {
+        None => raw,
+        Some(compression) => {
+            let mut bytes = Vec::new();
+
+            match compression {
+                "zlib" => ZlibDecoder::new(raw.as_slice()).read_to_end(&mut bytes)?,
+                "gzip" => GzDecoder::new(raw.as_slice()).read_to_end(&mut bytes)?,
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "Unsupported Tiled layer compression: {compression}"
+                    ))
+                }
+            };
+
+            bytes
+        }
+    };
+
+    anyhow::ensure!(bytes.len() % 4 == 0, "Truncated Tiled layer data");
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect())
+}
@@ -0,0 +1,356 @@
+pub mod descriptor;
+pub mod tmj;
+pub mod tmx;
+
+pub use descriptor::{
+    TiledDescriptor, TiledLayer, TiledLayerData, TiledObject, TiledTile, TiledTileset,
+};
+
+use super::{texture::TextureOptions, Shape, Texture, TextureAtlas, Upload};
+use crate::{
+    components::{
+        tilemap::{Tile, TileAnimation, TileFlags},
+        Properties, Sprite, Tag, TileProperties, Tilemap, Trans,
+    },
+    world::EntityManager,
+    Context, Id, World,
+};
+use image::RgbaImage;
+use nalgebra::{Rotation2, Vector2, Vector4};
+use parking_lot::RwLock;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::Arc,
+};
+use vulkano::image::sampler::Sampler;
+
+const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+const FLIPPED_DIAGONALLY: u32 = 0x2000_0000;
+const ROTATED_HEXAGONAL: u32 = 0x1000_0000;
+const GID_MASK: u32 =
+    !(FLIPPED_HORIZONTALLY | FLIPPED_VERTICALLY | FLIPPED_DIAGONALLY | ROTATED_HEXAGONAL);
+
+#[derive(Clone)]
+pub struct TiledMap {
+    pub descriptor: TiledDescriptor,
+    pub atlases: Vec<TextureAtlas>,
+}
+
+impl TiledMap {
+    pub fn new(descriptor: TiledDescriptor, textures: Vec<Texture>) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            textures.len() == descriptor.tilesets.len(),
+            "Tiled map has {} tilesets but {} textures were given",
+            descriptor.tilesets.len(),
+            textures.len()
+        );
+
+        let atlases = descriptor
+            .tilesets
+            .iter()
+            .zip(textures)
+            .map(|(tileset, texture)| {
+                let columns = tileset.columns.max(1);
+
+                TextureAtlas::grid(
+                    texture,
+                    tileset.tile_size,
+                    columns,
+                    tileset.tile_count.div_ceil(columns),
+                    Vector2::repeat(tileset.spacing),
+                    Vector2::repeat(tileset.margin),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            descriptor,
+            atlases,
+        })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        path: P,
+    ) -> anyhow::Result<Self> {
+        let descriptor = TiledDescriptor::from_file(path)?;
+        let textures = descriptor
+            .tilesets
+            .iter()
+            .map(|tileset| {
+                Texture::from_file(
+                    context,
+                    sampler.clone(),
+                    Self::image(tileset)?,
+                    TextureOptions::default(),
+                )
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Self::new(descriptor, textures)
+    }
+
+    pub fn decode<P: AsRef<Path>>(path: P) -> anyhow::Result<(TiledDescriptor, Vec<RgbaImage>)> {
+        let descriptor = TiledDescriptor::from_file(path)?;
+        let images = descriptor
+            .tilesets
+            .iter()
+            .map(|tileset| Texture::decode(Self::image(tileset)?))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok((descriptor, images))
+    }
+
+    pub fn upload(
+        context: &Context,
+        sampler: Arc<Sampler>,
+        descriptor: TiledDescriptor,
+        images: &[RgbaImage],
+    ) -> anyhow::Result<Upload<Self>> {
+        let textures = images
+            .iter()
+            .map(|image| {
+                Texture::upload(
+                    context,
+                    sampler.clone(),
+                    image.as_raw(),
+                    image.width(),
+                    image.height(),
+                    TextureOptions::default(),
+                )
+            })
+            .collect::<anyhow::Result<Upload<Vec<_>>>>()?;
+        let map = Self::new(descriptor, textures.value().clone())?;
+
+        Ok(textures.map(|_| map))
+    }
+
+    fn image(tileset: &TiledTileset) -> anyhow::Result<&Path> {
+        tileset.image.as_deref().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Tiled tileset {} is an image collection, which is not supported",
+                tileset.name
+            )
+        })
+    }
+
+    pub fn tile(&self, gid: u32) -> Option<(usize, Tile)> {
+        let id = gid & GID_MASK;
+        let (index, tileset) = self
+            .descriptor
+            .tilesets
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.first_gid <= id)
+            .max_by_key(|(_, t)| t.first_gid)?;
+
+        (id != 0).then(|| {
+            (
+                index,
+                Tile::with_flags(
+                    id - tileset.first_gid,
+                    TileFlags {
+                        flip_x: gid & FLIPPED_HORIZONTALLY != 0,
+                        flip_y: gid & FLIPPED_VERTICALLY != 0,
+                        diagonal: gid & FLIPPED_DIAGONALLY != 0,
+                        rotated_hexagonal: gid & ROTATED_HEXAGONAL != 0,
+                    },
+                ),
+            )
+        })
+    }
+
+    pub fn spawn(&self, context: &Context, world: &World, layer: u32) -> anyhow::Result<Vec<Id>> {
+        let d = &self.descriptor;
+        let mut em = world.em.write();
+        let mut entities = Vec::new();
+        let count = d.layers.len() as u32;
+
+        for (i, l) in d.layers.iter().enumerate() {
+            let depth = layer + count - 1 - i as u32;
+            let color = Vector4::new(1.0, 1.0, 1.0, l.opacity);
+
+            match &l.data {
+                TiledLayerData::Tiles(gids) => {
+                    anyhow::ensure!(
+                        gids.len() == (d.size.x * d.size.y) as usize,
+                        "Tiled layer {} has {} tiles but the map is {}x{}",
+                        l.name,
+                        gids.len(),
+                        d.size.x,
+                        d.size.y
+                    );
+
+                    let mut tilemaps = BTreeMap::new();
+
+                    for (index, gid) in gids.iter().enumerate() {
+                        let Some((tileset, tile)) = self.tile(*gid) else {
+                            continue;
+                        };
+                        let tilemap = tilemaps.entry(tileset).or_insert_with(|| {
+                            self.tilemap(tileset, &self.atlases[tileset], l, depth, color)
+                        });
+                        let cell = Vector2::new(index as u32 % d.size.x, index as u32 / d.size.x);
+
+                        tilemap.write().set(0, cell, Some(tile));
+                    }
+
+                    for (tileset, tilemap) in tilemaps {
+                        let e = em.add(true);
+                        let tiles: HashMap<_, _> = d.tilesets[tileset]
+                            .tiles
+                            .iter()
+                            .filter(|(_, t)| !t.class.is_empty() || !t.properties.is_empty())
+                            .map(|(id, t)| {
+                                (*id, (t.class.clone(), Properties(t.properties.clone())))
+                            })
+                            .collect();
+
+                        em.add_component(e, Trans::new(l.offset, 0.0, Vector2::new(1.0, 1.0)));
+                        em.add_component(e, Tag::new(l.name.clone()));
+                        em.add_component(e, tilemap);
+
+                        if !l.properties.is_empty() {
+                            em.add_component(e, Properties::new(l.properties.clone()));
+                        }
+
+                        if !tiles.is_empty() {
+                            em.add_component(e, TileProperties::new(tiles));
+                        }
+
+                        entities.push(e);
+                    }
+                }
+                TiledLayerData::Objects(objects) => {
+                    for o in objects {
+                        entities.push(self.object(context, &mut em, l, o, depth, color)?);
+                    }
+                }
+            }
+        }
+
+        Ok(entities)
+    }
+
+    fn tilemap(
+        &self,
+        tileset: usize,
+        atlas: &TextureAtlas,
+        l: &TiledLayer,
+        layer: u32,
+        color: Vector4<f32>,
+    ) -> Arc<RwLock<Tilemap>> {
+        let d = &self.descriptor;
+        let tilemap = Tilemap::new(atlas.clone(), d.size, d.tile_size.cast(), d.layout);
+
+        {
+            let mut t = tilemap.write();
+            let index = t.add_layer(l.name.clone(), layer);
+
+            if let Some(tl) = t.layer_mut(index) {
+                tl.visible = l.visible;
+                tl.color = color;
+            }
+
+            for (id, tile) in &d.tilesets[tileset].tiles {
+                if !tile.animation.is_empty() {
+                    t.set_animation(*id, Some(TileAnimation::new(tile.animation.clone())));
+                }
+            }
+        }
+
+        tilemap
+    }
+
+    fn object(
+        &self,
+        context: &Context,
+        em: &mut EntityManager,
+        l: &TiledLayer,
+        o: &TiledObject,
+        layer: u32,
+        color: Vector4<f32>,
+    ) -> anyhow::Result<Id> {
+        let tile = o.gid.and_then(|gid| self.tile(gid));
+        let data =
+            tile.and_then(|(tileset, tile)| self.descriptor.tilesets[tileset].tiles.get(&tile.id));
+        let (flags, angle) = match tile {
+            Some((_, tile)) if self.descriptor.layout.is_hexagonal() => tile.flags.hexagonal(),
+            Some((_, tile)) => (tile.flags, 0.0),
+            None => (TileFlags::default(), 0.0),
+        };
+        let center = if tile.is_some() {
+            Vector2::new(o.size.x, -o.size.y) / 2.0
+        } else {
+            o.size / 2.0
+        };
+        let rotation = o.rotation.to_radians();
+        let position = l.offset + o.position + Rotation2::new(rotation) * center;
+        let e = em.add(l.visible && o.visible);
+        let class = match data {
+            Some(t) if o.class.is_empty() => t.class.clone(),
+            _ => o.class.clone(),
+        };
+        let mut properties = data.map(|t| t.properties.clone()).unwrap_or_default();
+
+        properties.extend(o.properties.clone());
+
+        em.add_component(
+            e,
+            Trans::new(position, rotation + angle, Vector2::new(1.0, 1.0)),
+        );
+        em.add_component(
+            e,
+            Tag::new(if o.name.is_empty() {
+                class
+            } else {
+                o.name.clone()
+            }),
+        );
+
+        if !properties.is_empty() {
+            em.add_component(e, Properties::new(properties));
+        }
+
+        if let Some((tileset, tile)) = tile {
+            let atlas = &self.atlases[tileset];
+
+            if let Some(mut region) = atlas.frame(tile.id as usize) {
+                let (flip_x, flip_y) = if flags.diagonal {
+                    region = region.rotated();
+
+                    (!flags.flip_y, flags.flip_x)
+                } else {
+                    (flags.flip_x, flags.flip_y)
+                };
+
+                if flip_x {
+                    region.offset.x += region.size.x;
+                    region.size.x = -region.size.x;
+                }
+
+                if flip_y {
+                    region.offset.y += region.size.y;
+                    region.size.y = -region.size.y;
+                }
+
+                let sprite = Sprite::new(
+                    context,
+                    Shape::rect(context, o.size)?,
+                    atlas.texture.clone(),
+                    color,
+                    layer,
+                )?;
+
+                sprite.write().region = region;
+
+                em.add_component(e, sprite);
+            }
+        }
+
+        Ok(e)
+    }
+}
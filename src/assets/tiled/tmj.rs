@@ -0,0 +1,209 @@
+use super::descriptor::{
+    self, Group, TiledDescriptor, TiledLayer, TiledLayerData, TiledObject, TiledTile, TiledTileset,
+};
+use crate::components::properties::Property;
+use anyhow::Context as _;
+use nalgebra::Vector2;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+fn get<T: DeserializeOwned>(value: &Value, key: &str, default: T) -> anyhow::Result<T> {
+    value.get(key).map_or(Ok(default), |v| {
+        serde_json::from_value(v.clone())
+            .with_context(|| format!("Tiled JSON has an invalid {key}"))
+    })
+}
+
+fn require<T: DeserializeOwned>(value: &Value, key: &str) -> anyhow::Result<T> {
+    serde_json::from_value(value.get(key).cloned().unwrap_or_default())
+        .with_context(|| format!("Tiled JSON has a missing or invalid {key}"))
+}
+
+fn text(value: &Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn class(value: &Value) -> String {
+    value
+        .get("class")
+        .or(value.get("type"))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn properties(value: &Value) -> anyhow::Result<HashMap<String, Property>> {
+    array(value, "properties")
+        .map(|p| {
+            let kind = p.get("type").and_then(Value::as_str).unwrap_or("string");
+            let property = match p.get("value") {
+                Some(Value::String(s)) => descriptor::property(kind, s)?,
+                Some(Value::Bool(b)) => Property::Bool(*b),
+                Some(Value::Number(n)) if kind == "float" => {
+                    Property::Float(n.as_f64().unwrap_or_default())
+                }
+                Some(Value::Number(n)) => match n.as_i64() {
+                    Some(i) => Property::Int(i),
+                    None => Property::Float(n.as_f64().unwrap_or_default()),
+                },
+                Some(v) => Property::String(v.to_string()),
+                None => Property::String(String::new()),
+            };
+
+            Ok((require(p, "name")?, property))
+        })
+        .collect()
+}
+
+pub fn parse_map(text: &str) -> anyhow::Result<TiledDescriptor> {
+    let map: Value = serde_json::from_str(text).context("Failed to parse Tiled map JSON")?;
+
+    anyhow::ensure!(
+        !get(&map, "infinite", false)?,
+        "Infinite Tiled maps are not supported"
+    );
+
+    let mut layers = Vec::new();
+
+    parse_layers(&map, Group::default(), &mut layers)?;
+
+    Ok(TiledDescriptor {
+        size: Vector2::new(require(&map, "width")?, require(&map, "height")?),
+        tile_size: Vector2::new(require(&map, "tilewidth")?, require(&map, "tileheight")?),
+        layout: descriptor::layout(
+            map.get("orientation")
+                .and_then(Value::as_str)
+                .unwrap_or("orthogonal"),
+            get(&map, "hexsidelength", 0.0)?,
+            map.get("staggeraxis").and_then(Value::as_str),
+            map.get("staggerindex").and_then(Value::as_str),
+        )?,
+        tilesets: array(&map, "tilesets")
+            .map(tileset)
+            .collect::<anyhow::Result<_>>()?,
+        layers,
+        properties: properties(&map)?,
+    })
+}
+
+pub fn parse_tileset(text: &str) -> anyhow::Result<TiledTileset> {
+    tileset(&serde_json::from_str(text).context("Failed to parse Tiled tileset JSON")?)
+}
+
+fn tileset(value: &Value) -> anyhow::Result<TiledTileset> {
+    Ok(TiledTileset {
+        first_gid: get(value, "firstgid", 0)?,
+        source: value
+            .get("source")
+            .and_then(Value::as_str)
+            .map(String::from),
+        name: text(value, "name"),
+        tile_size: Vector2::new(get(value, "tilewidth", 0)?, get(value, "tileheight", 0)?),
+        columns: get(value, "columns", 0)?,
+        tile_count: get(value, "tilecount", 0)?,
+        margin: get(value, "margin", 0)?,
+        spacing: get(value, "spacing", 0)?,
+        image: value
+            .get("image")
+            .and_then(Value::as_str)
+            .map(PathBuf::from),
+        tiles: array(value, "tiles")
+            .map(|t| {
+                let animation = array(t, "animation")
+                    .map(|f| {
+                        Ok((
+                            require(f, "tileid")?,
+                            Duration::from_millis(require(f, "duration")?),
+                        ))
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+
+                Ok((
+                    require(t, "id")?,
+                    TiledTile {
+                        class: class(t),
+                        properties: properties(t)?,
+                        animation,
+                    },
+                ))
+            })
+            .collect::<anyhow::Result<_>>()?,
+    })
+}
+
+fn parse_layers(value: &Value, group: Group, layers: &mut Vec<TiledLayer>) -> anyhow::Result<()> {
+    for l in array(value, "layers") {
+        let nested = group.nest(
+            Vector2::new(get(l, "offsetx", 0.0)?, get(l, "offsety", 0.0)?),
+            get(l, "opacity", 1.0)?,
+            get(l, "visible", true)?,
+        );
+        let data = match l.get("type").and_then(Value::as_str) {
+            Some("group") => {
+                parse_layers(l, nested, layers)?;
+
+                continue;
+            }
+            Some("tilelayer") => TiledLayerData::Tiles(tiles(l)?),
+            Some("objectgroup") => TiledLayerData::Objects(
+                array(l, "objects")
+                    .map(object)
+                    .collect::<anyhow::Result<_>>()?,
+            ),
+            _ => continue,
+        };
+
+        layers.push(TiledLayer {
+            name: text(l, "name"),
+            class: class(l),
+            visible: nested.visible,
+            opacity: nested.opacity,
+            offset: nested.offset,
+            properties: properties(l)?,
+            data,
+        });
+    }
+
+    Ok(())
+}
+
+fn tiles(layer: &Value) -> anyhow::Result<Vec<u32>> {
+    match layer.get("data") {
+        Some(Value::String(data)) => descriptor::decode(
+            data,
+            layer
+                .get("encoding")
+                .and_then(Value::as_str)
+                .unwrap_or("base64"),
+            layer.get("compression").and_then(Value::as_str),
+        ),
+        _ => require(layer, "data"),
+    }
+}
+
+fn object(value: &Value) -> anyhow::Result<TiledObject> {
+    Ok(TiledObject {
+        id: get(value, "id", 0)?,
+        name: text(value, "name"),
+        class: class(value),
+        position: Vector2::new(get(value, "x", 0.0)?, get(value, "y", 0.0)?),
+        size: Vector2::new(get(value, "width", 0.0)?, get(value, "height", 0.0)?),
+        rotation: get(value, "rotation", 0.0)?,
+        gid: get(value, "gid", None)?,
+        visible: get(value, "visible", true)?,
+        properties: properties(value)?,
+    })
+}
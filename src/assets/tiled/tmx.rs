@@ -0,0 +1,217 @@
+use super::descriptor::{
+    self, Group, TiledDescriptor, TiledLayer, TiledLayerData, TiledObject, TiledTile, TiledTileset,
+};
+use crate::components::properties::Property;
+use anyhow::Context as _;
+use nalgebra::Vector2;
+use roxmltree::{Document, Node};
+use std::{collections::HashMap, path::PathBuf, str::FromStr, time::Duration};
+
+fn get<T: FromStr>(node: Node, key: &str, default: T) -> anyhow::Result<T> {
+    node.attribute(key).map_or(Ok(default), |v| {
+        v.parse()
+            .ok()
+            .with_context(|| format!("Tiled {} has an invalid {key}", node.tag_name().name()))
+    })
+}
+
+fn require<T: FromStr>(node: Node, key: &str) -> anyhow::Result<T> {
+    node.attribute(key)
+        .and_then(|v| v.parse().ok())
+        .with_context(|| {
+            format!(
+                "Tiled {} has a missing or invalid {key}",
+                node.tag_name().name()
+            )
+        })
+}
+
+fn text(node: Node, key: &str) -> String {
+    node.attribute(key).unwrap_or_default().to_string()
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    tag: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(move |n| n.has_tag_name(tag))
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, tag: &'static str) -> Option<Node<'a, 'input>> {
+    children(node, tag).next()
+}
+
+fn class(node: Node) -> String {
+    node.attribute("class")
+        .or(node.attribute("type"))
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn properties(node: Node) -> anyhow::Result<HashMap<String, Property>> {
+    child(node, "properties")
+        .into_iter()
+        .flat_map(|p| children(p, "property"))
+        .map(|p| {
+            let value = p.attribute("value").or(p.text()).unwrap_or_default();
+
+            Ok((
+                require(p, "name")?,
+                descriptor::property(p.attribute("type").unwrap_or("string"), value)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn parse_map(text: &str) -> anyhow::Result<TiledDescriptor> {
+    let document = Document::parse(text).context("Failed to parse Tiled map XML")?;
+    let map = document.root_element();
+
+    anyhow::ensure!(map.has_tag_name("map"), "Tiled map XML has no map element");
+    anyhow::ensure!(
+        get(map, "infinite", 0u32)? == 0,
+        "Infinite Tiled maps are not supported"
+    );
+
+    let mut layers = Vec::new();
+
+    parse_layers(map, Group::default(), &mut layers)?;
+
+    Ok(TiledDescriptor {
+        size: Vector2::new(require(map, "width")?, require(map, "height")?),
+        tile_size: Vector2::new(require(map, "tilewidth")?, require(map, "tileheight")?),
+        layout: descriptor::layout(
+            map.attribute("orientation").unwrap_or("orthogonal"),
+            get(map, "hexsidelength", 0.0)?,
+            map.attribute("staggeraxis"),
+            map.attribute("staggerindex"),
+        )?,
+        tilesets: children(map, "tileset")
+            .map(tileset)
+            .collect::<anyhow::Result<_>>()?,
+        layers,
+        properties: properties(map)?,
+    })
+}
+
+pub fn parse_tileset(text: &str) -> anyhow::Result<TiledTileset> {
+    let document = Document::parse(text).context("Failed to parse Tiled tileset XML")?;
+    let node = document.root_element();
+
+    anyhow::ensure!(
+        node.has_tag_name("tileset"),
+        "Tiled tileset XML has no tileset element"
+    );
+
+    tileset(node)
+}
+
+fn tileset(node: Node) -> anyhow::Result<TiledTileset> {
+    Ok(TiledTileset {
+        first_gid: get(node, "firstgid", 0)?,
+        source: node.attribute("source").map(String::from),
+        name: text(node, "name"),
+        tile_size: Vector2::new(get(node, "tilewidth", 0)?, get(node, "tileheight", 0)?),
+        columns: get(node, "columns", 0)?,
+        tile_count: get(node, "tilecount", 0)?,
+        margin: get(node, "margin", 0)?,
+        spacing: get(node, "spacing", 0)?,
+        image: child(node, "image")
+            .and_then(|i| i.attribute("source"))
+            .map(PathBuf::from),
+        tiles: children(node, "tile")
+            .map(|t| {
+                let animation = child(t, "animation")
+                    .into_iter()
+                    .flat_map(|a| children(a, "frame"))
+                    .map(|f| {
+                        Ok((
+                            require(f, "tileid")?,
+                            Duration::from_millis(require(f, "duration")?),
+                        ))
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+
+                Ok((
+                    require(t, "id")?,
+                    TiledTile {
+                        class: class(t),
+                        properties: properties(t)?,
+                        animation,
+                    },
+                ))
+            })
+            .collect::<anyhow::Result<_>>()?,
+    })
+}
+
+fn parse_layers(node: Node, group: Group, layers: &mut Vec<TiledLayer>) -> anyhow::Result<()> {
+    for n in node.children().filter(|n| n.is_element()) {
+        let tag = n.tag_name().name();
+
+        if !matches!(tag, "layer" | "objectgroup" | "group") {
+            continue;
+        }
+
+        let nested = group.nest(
+            Vector2::new(get(n, "offsetx", 0.0)?, get(n, "offsety", 0.0)?),
+            get(n, "opacity", 1.0)?,
+            get(n, "visible", 1u32)? != 0,
+        );
+        let data = match tag {
+            "group" => {
+                parse_layers(n, nested, layers)?;
+
+                continue;
+            }
+            "layer" => TiledLayerData::Tiles(tiles(n)?),
+            _ => TiledLayerData::Objects(
+                children(n, "object")
+                    .map(object)
+                    .collect::<anyhow::Result<_>>()?,
+            ),
+        };
+
+        layers.push(TiledLayer {
+            name: text(n, "name"),
+            class: class(n),
+            visible: nested.visible,
+            opacity: nested.opacity,
+            offset: nested.offset,
+            properties: properties(n)?,
+            data,
+        });
+    }
+
+    Ok(())
+}
+
+fn tiles(layer: Node) -> anyhow::Result<Vec<u32>> {
+    let data = child(layer, "data").context("Tiled layer has no data")?;
+
+    match data.attribute("encoding") {
+        Some(encoding) => descriptor::decode(
+            data.text().unwrap_or_default(),
+            encoding,
+            data.attribute("compression"),
+        ),
+        None => children(data, "tile").map(|t| get(t, "gid", 0)).collect(),
+    }
+}
+
+fn object(node: Node) -> anyhow::Result<TiledObject> {
+    Ok(TiledObject {
+        id: get(node, "id", 0)?,
+        name: text(node, "name"),
+        class: class(node),
+        position: Vector2::new(get(node, "x", 0.0)?, get(node, "y", 0.0)?),
+        size: Vector2::new(get(node, "width", 0.0)?, get(node, "height", 0.0)?),
+        rotation: get(node, "rotation", 0.0)?,
+        gid: node
+            .attribute("gid")
+            .map(|_| require(node, "gid"))
+            .transpose()?,
+        visible: get(node, "visible", 1u32)? != 0,
+        properties: properties(node)?,
+    })
+}
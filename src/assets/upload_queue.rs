@@ -0,0 +1,118 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use vulkano::command_buffer::{
+    allocator::StandardCommandBufferAllocator,
+    auto::{AutoCommandBufferBuilder, PrimaryAutoCommandBuffer},
+};
+
+pub type UploadBuilder = AutoCommandBufferBuilder<
+    PrimaryAutoCommandBuffer<Arc<StandardCommandBufferAllocator>>,
+    Arc<StandardCommandBufferAllocator>,
+>;
+pub type UploadCommand = Box<dyn Fn(&mut UploadBuilder) -> anyhow::Result<()> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct Upload<T> {
+    value: T,
+    ready: Vec<Arc<AtomicBool>>,
+}
+
+impl<T> Upload<T> {
+    pub fn ready(value: T) -> Self {
+        Self {
+            value,
+            ready: Vec::new(),
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.iter().all(|r| r.load(Ordering::Acquire))
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        self.is_ready().then_some(&self.value)
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Upload<U> {
+        Upload {
+            value: f(self.value),
+            ready: self.ready,
+        }
+    }
+}
+
+impl<T> FromIterator<Upload<T>> for Upload<Vec<T>> {
+    fn from_iter<I: IntoIterator<Item = Upload<T>>>(iter: I) -> Self {
+        let mut upload = Upload::ready(Vec::new());
+
+        for Upload { value, ready } in iter {
+            upload.value.push(value);
+            upload.ready.extend(ready);
+        }
+
+        upload
+    }
+}
+
+#[derive(Default)]
+pub struct UploadQueue {
+    commands: Vec<(UploadCommand, Arc<AtomicBool>)>,
+}
+
+impl UploadQueue {
+    pub fn push<T, F>(&mut self, value: T, command: F) -> Upload<T>
+    where
+        F: Fn(&mut UploadBuilder) -> anyhow::Result<()> + Send + Sync + 'static,
+    {
+        let ready = Arc::new(AtomicBool::new(false));
+
+        self.commands.push((Box::new(command), ready.clone()));
+
+        Upload {
+            value,
+            ready: vec![ready],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+
+    pub fn requeue(&mut self, mut uploads: Self) {
+        uploads.commands.append(&mut self.commands);
+
+        self.commands = uploads.commands;
+    }
+
+    pub fn record(&self, builder: &mut UploadBuilder) -> anyhow::Result<()> {
+        for (command, _) in &self.commands {
+            command(builder)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn complete(self) {
+        for (_, ready) in self.commands {
+            ready.store(true, Ordering::Release);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::assets::{animation::Playback, texture_atlas::Region, Animation, Texture};
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnimationEvent {
+    Finished(String),
+    Looped(String),
+}
+
+#[derive(Clone)]
+pub struct Animator {
+    pub clips: HashMap<String, Animation>,
+    pub transitions: HashMap<String, String>,
+    pub speed: f32,
+    clip: Option<String>,
+    cursor: usize,
+    time: Duration,
+    forward: bool,
+    finished: bool,
+    events: Vec<AnimationEvent>,
+}
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self {
+            clips: HashMap::new(),
+            transitions: HashMap::new(),
+            speed: 1.0,
+            clip: None,
+            cursor: 0,
+            time: Duration::ZERO,
+            forward: true,
+            finished: false,
+            events: Vec::new(),
+        }
+    }
+}
+
+impl Animator {
+    pub fn new() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Default::default()))
+    }
+
+    pub fn add_clip<S: Into<String>>(&mut self, name: S, animation: Animation) {
+        self.clips.insert(name.into(), animation);
+    }
+
+    pub fn add_transition<S: Into<String>>(&mut self, from: S, to: S) {
+        self.transitions.insert(from.into(), to.into());
+    }
+
+    pub fn clip(&self) -> Option<&str> {
+        self.clip.as_deref()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn play(&mut self, name: &str) -> bool {
+        if self.clip.as_deref() == Some(name) && !self.finished {
+            return true;
+        }
+
+        self.restart(name)
+    }
+
+    pub fn restart(&mut self, name: &str) -> bool {
+        if !self.clips.contains_key(name) {
+            return false;
+        }
+
+        self.clip = Some(name.to_string());
+        self.cursor = 0;
+        self.time = Duration::ZERO;
+        self.forward = true;
+        self.finished = false;
+
+        true
+    }
+
+    pub fn stop(&mut self) {
+        self.clip = None;
+    }
+
+    pub fn events(&self) -> &[AnimationEvent] {
+        &self.events
+    }
+
+    pub fn take_events(&mut self) -> Vec<AnimationEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    pub fn frame(&self) -> Option<(Texture, Region)> {
+        let animation = self.clips.get(self.clip.as_ref()?)?;
+
+        Some((
+            animation.atlas.texture.clone(),
+            animation.region(self.cursor)?,
+        ))
+    }
+
+    pub fn advance(&mut self, delta: Duration) {
+        self.events.clear();
+
+        let Some(name) = self.clip.clone() else {
+            return;
+        };
+        let Some(animation) = self.clips.get(&name) else {
+            return;
+        };
+        let len = animation.len();
+
+        if self.finished || len == 0 {
+            return;
+        }
+
+        self.time += delta.mul_f32(self.speed.max(0.0));
+
+        while let Some(&(_, duration)) = animation.frames.get(self.cursor) {
+            if duration.is_zero() || self.time < duration {
+                break;
+            }
+
+            self.time -= duration;
+
+            match animation.playback {
+                Playback::Loop => {
+                    self.cursor = (self.cursor + 1) % len;
+
+                    if self.cursor == 0 {
+                        self.events.push(AnimationEvent::Looped(name.clone()));
+                    }
+                }
+                Playback::PingPong => {
+                    if self.forward && self.cursor + 1 < len {
+                        self.cursor += 1;
+                    } else if !self.forward && self.cursor > 0 {
+                        self.cursor -= 1;
+
+                        if self.cursor == 0 {
+                            self.events.push(AnimationEvent::Looped(name.clone()));
+                        }
+                    } else {
+                        self.forward = !self.forward;
+                        self.cursor = if self.forward {
+                            1.min(len - 1)
+                        } else {
+                            self.cursor.saturating_sub(1)
+                        };
+                    }
+                }
+                Playback::Once => {
+                    if self.cursor + 1 < len {
+                        self.cursor += 1;
+                    } else {
+                        self.finished = true;
+                        self.events.push(AnimationEvent::Finished(name.clone()));
+
+                        if let Some(next) = self.transitions.get(&name).cloned() {
+                            self.restart(&next);
+                        }
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::{vertex, BitmapTextEntity, Drawable};
+use crate::{
+    components::{sprite::fragment, Camera, Trans},
+    world::{renderer_manager::Draw, World},
+    Context, Id,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::{
+    buffer::{
+        allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo},
+        BufferUsage,
+    },
+    descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet},
+    memory::allocator::MemoryTypeFilter,
+    padded::Padded,
+    pipeline::{Pipeline, PipelineBindPoint},
+};
+
+pub struct BitmapTextDrawable;
+
+impl BitmapTextDrawable {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+impl Drawable<BitmapTextEntity> for BitmapTextDrawable {
+    fn draw(
+        self: Arc<Self>,
+        (_, b, t): BitmapTextEntity,
+        (_, c, ct): (Id, Arc<RwLock<Camera>>, Arc<RwLock<Trans>>),
+        (_, builder, recreate_swapchain, _): &mut Draw,
+        context: Arc<RwLock<Context>>,
+        _: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        let context = context.read();
+        let c = c.read();
+        let ct = ct.read();
+        let mut b = b.write();
+        let t = t.read();
+
+        if *recreate_swapchain || b.is_pipeline_outdated() {
+            b.recreate_pipeline(&context)?;
+        }
+
+        let pages = b.pages(&context)?.to_vec();
+        let pipeline = b.pipeline.clone();
+
+        builder.bind_pipeline_graphics(pipeline.clone())?;
+
+        let uniform_allocator = SubbufferAllocator::new(
+            context.memory_allocator.clone(),
+            SubbufferAllocatorCreateInfo {
+                buffer_usage: BufferUsage::UNIFORM_BUFFER,
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+        );
+        let layouts = pipeline.layout().set_layouts();
+        let view = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = vertex::View {
+                z: Padded(-(b.layer as f32)),
+                transform: <[[f32; 3]; 3]>::from(t.matrix()).map(Padded),
+                camera_transform: <[[f32; 3]; 3]>::from(ct.matrix()).map(Padded),
+                camera_proj: c.proj().into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[0].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+        let color = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = fragment::Color {
+                color: b.color.into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[2].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+
+        for (page, instances) in pages {
+            let texture = &b.font().pages[page];
+            let set = PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[1].clone(),
+                [
+                    WriteDescriptorSet::sampler(0, texture.sampler.clone()),
+                    WriteDescriptorSet::image_view(1, texture.image.clone()),
+                ],
+                [],
+            )?;
+
+            builder
+                .bind_descriptor_sets(
+                    PipelineBindPoint::Graphics,
+                    pipeline.layout().clone(),
+                    0,
+                    vec![view.clone(), set, color.clone()],
+                )?
+                .bind_vertex_buffers(0, (b.shape.vertices.clone(), instances.clone()))?;
+
+            if let Some(indices) = &b.shape.indices {
+                builder.bind_index_buffer(indices.clone())?.draw_indexed(
+                    indices.len() as u32,
+                    instances.len() as u32,
+                    0,
+                    0,
+                    0,
+                )?;
+            } else {
+                builder.draw(b.shape.vertices.len() as u32, instances.len() as u32, 0, 0)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use vulkano::{buffer::BufferContents, pipeline::graphics::vertex_input::Vertex};
+
+#[derive(BufferContents, Vertex, Copy, Clone)]
+#[repr(C)]
+pub struct GlyphInstance {
+    #[format(R32G32_SFLOAT)]
+    pub glyph_offset: [f32; 2],
+    #[format(R32G32_SFLOAT)]
+    pub glyph_size: [f32; 2],
+    #[format(R32G32B32A32_SFLOAT)]
+    pub glyph_region: [f32; 4],
+}
@@ -0,0 +1,295 @@
+pub mod bitmap_text_drawable;
+pub mod glyph_instance;
+pub mod vertex;
+
+pub use bitmap_text_drawable::BitmapTextDrawable;
+pub use glyph_instance::GlyphInstance;
+
+use crate::{
+    assets::{
+        shape::{Bounds, Vertex2},
+        BitmapFont, Shape,
+    },
+    components::{sprite::fragment, text::TextAlign, Trans},
+    Context, Drawable, Id,
+};
+use nalgebra::{Vector2, Vector4};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::{
+    buffer::{BufferUsage, Subbuffer},
+    pipeline::{
+        graphics::{
+            color_blend::{
+                AttachmentBlend, BlendFactor, ColorBlendAttachmentState, ColorBlendState,
+            },
+            depth_stencil::{CompareOp, DepthState, DepthStencilState},
+            input_assembly::{InputAssemblyState, PrimitiveTopology},
+            multisample::MultisampleState,
+            rasterization::RasterizationState,
+            vertex_input::{Vertex, VertexDefinition},
+            viewport::ViewportState,
+            GraphicsPipelineCreateInfo,
+        },
+        layout::PipelineDescriptorSetLayoutCreateInfo,
+        DynamicState, GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo,
+    },
+    render_pass::Subpass,
+};
+
+pub type BitmapTextEntity = (Id, Arc<RwLock<BitmapText>>, Arc<RwLock<Trans>>);
+pub type GlyphPage = (usize, Subbuffer<[GlyphInstance]>);
+
+pub struct BitmapText {
+    font: BitmapFont,
+    text: String,
+    align: TextAlign,
+    line_spacing: f32,
+    scale: f32,
+    pub color: Vector4<f32>,
+    pub layer: u32,
+    pub sort_key: Option<f32>,
+    pub shape: Shape,
+    glyphs: Vec<Vec<GlyphInstance>>,
+    pages: Option<Vec<GlyphPage>>,
+    bounds: Bounds,
+    pub drawable: Arc<dyn Drawable<BitmapTextEntity>>,
+    pub pipeline: Arc<GraphicsPipeline>,
+}
+
+impl BitmapText {
+    pub fn new(
+        context: &Context,
+        font: BitmapFont,
+        text: impl Into<String>,
+        color: Vector4<f32>,
+        layer: u32,
+    ) -> anyhow::Result<Arc<RwLock<Self>>> {
+        let shape = Shape::rect(context, Vector2::new(1.0, 1.0))?;
+        let mut bitmap_text = Self {
+            font,
+            text: text.into(),
+            align: TextAlign::default(),
+            line_spacing: 1.0,
+            scale: 1.0,
+            color,
+            layer,
+            sort_key: None,
+            pipeline: Self::pipeline(context, shape.topology)?,
+            shape,
+            glyphs: Vec::new(),
+            pages: None,
+            bounds: Bounds::new(Vector2::zeros(), Vector2::zeros()),
+            drawable: BitmapTextDrawable::new(),
+        };
+
+        bitmap_text.layout();
+
+        Ok(Arc::new(RwLock::new(bitmap_text)))
+    }
+
+    pub fn font(&self) -> &BitmapFont {
+        &self.font
+    }
+
+    pub fn set_font(&mut self, font: BitmapFont) {
+        self.font = font;
+
+        self.layout();
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let text = text.into();
+
+        if self.text != text {
+            self.text = text;
+
+            self.layout();
+        }
+    }
+
+    pub fn align(&self) -> TextAlign {
+        self.align
+    }
+
+    pub fn set_align(&mut self, align: TextAlign) {
+        self.align = align;
+
+        self.layout();
+    }
+
+    pub fn line_spacing(&self) -> f32 {
+        self.line_spacing
+    }
+
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.line_spacing = line_spacing;
+
+        self.layout();
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+
+        self.layout();
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    pub fn pages(&mut self, context: &Context) -> anyhow::Result<&[GlyphPage]> {
+        let pages = match self.pages.take() {
+            Some(pages) => pages,
+            None => self
+                .glyphs
+                .iter()
+                .enumerate()
+                .filter(|(_, g)| !g.is_empty())
+                .map(|(page, g)| Ok((page, Shape::buffer(context, BufferUsage::VERTEX_BUFFER, g)?)))
+                .collect::<anyhow::Result<_>>()?,
+        };
+
+        Ok(self.pages.insert(pages))
+    }
+
+    pub fn recreate_pipeline(&mut self, context: &Context) -> anyhow::Result<()> {
+        self.pipeline = Self::pipeline(context, self.shape.topology)?;
+
+        Ok(())
+    }
+
+    pub fn is_pipeline_outdated(&self) -> bool {
+        self.pipeline.input_assembly_state().topology != self.shape.topology
+    }
+
+    pub fn pipeline(
+        context: &Context,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        context.pipeline(("bitmap_text", topology), || {
+            Self::create_pipeline(context, topology)
+        })
+    }
+
+    fn layout(&mut self) {
+        let mut glyphs = vec![Vec::new(); self.font.pages.len()];
+        let mut corners = Vec::new();
+
+        for (i, line) in self.text.split('\n').enumerate() {
+            let mut x = 0.0;
+            let mut last = None;
+            let mut quads = Vec::new();
+
+            for c in line.chars() {
+                if let Some(last) = last {
+                    x += self.font.kerning(last, c);
+                }
+
+                if let Some(glyph) = self.font.glyph(c) {
+                    quads.push((Vector2::new(x, 0.0) + glyph.offset, *glyph));
+
+                    x += glyph.advance;
+                }
+
+                last = Some(c);
+            }
+
+            let shift = Vector2::new(
+                match self.align {
+                    TextAlign::Left => 0.0,
+                    TextAlign::Center => -x / 2.0,
+                    TextAlign::Right => -x,
+                },
+                i as f32 * self.font.line_height * self.line_spacing,
+            );
+
+            for (offset, glyph) in quads {
+                let offset = (offset + shift) * self.scale;
+                let size = glyph.size * self.scale;
+
+                corners.extend([offset, offset + size]);
+
+                if let Some(page) = glyphs.get_mut(glyph.page) {
+                    page.push(GlyphInstance {
+                        glyph_offset: offset.into(),
+                        glyph_size: size.into(),
+                        glyph_region: glyph.region.to_vector().into(),
+                    });
+                }
+            }
+        }
+
+        self.bounds = if corners.is_empty() {
+            Bounds::new(Vector2::zeros(), Vector2::zeros())
+        } else {
+            Bounds::from_points(corners)
+        };
+        self.glyphs = glyphs;
+        self.pages = None;
+    }
+
+    fn create_pipeline(
+        context: &Context,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        let vertex = context.shader("bitmap_text::vertex", vertex::load)?;
+        let fragment = context.shader("sprite::fragment", fragment::load)?;
+        let vertex_input_state = [Vertex2::per_vertex(), GlyphInstance::per_instance()]
+            .definition(&vertex.info().input_interface)?;
+        let stages = [
+            PipelineShaderStageCreateInfo::new(vertex),
+            PipelineShaderStageCreateInfo::new(fragment),
+        ];
+        let layout = PipelineLayout::new(
+            context.device.clone(),
+            PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+                .into_pipeline_layout_create_info(context.device.clone())?,
+        )?;
+        let subpass = Subpass::from(context.render_pass.clone(), 0).unwrap();
+
+        Ok(GraphicsPipeline::new(
+            context.device.clone(),
+            None,
+            GraphicsPipelineCreateInfo {
+                stages: stages.into_iter().collect(),
+                vertex_input_state: Some(vertex_input_state),
+                input_assembly_state: Some(InputAssemblyState {
+                    topology,
+                    ..Default::default()
+                }),
+                viewport_state: Some(ViewportState::default()),
+                rasterization_state: Some(RasterizationState::default()),
+                depth_stencil_state: Some(DepthStencilState {
+                    depth: Some(DepthState {
+                        write_enable: false,
+                        compare_op: CompareOp::LessOrEqual,
+                    }),
+                    ..Default::default()
+                }),
+                multisample_state: Some(MultisampleState::default()),
+                color_blend_state: Some(ColorBlendState::with_attachment_states(
+                    subpass.num_color_attachments(),
+                    ColorBlendAttachmentState {
+                        blend: Some(AttachmentBlend {
+                            src_alpha_blend_factor: BlendFactor::One,
+                            ..AttachmentBlend::alpha()
+                        }),
+                        ..Default::default()
+                    },
+                )),
+                dynamic_state: [DynamicState::Viewport].into_iter().collect(),
+                subpass: Some(subpass.into()),
+                ..GraphicsPipelineCreateInfo::layout(layout)
+            },
+        )?)
+    }
+}
@@ -0,0 +1,32 @@
+vulkano_shaders::shader! {
+    ty: "vertex",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 position;
+layout(location = 1) in vec2 uv;
+layout(location = 2) in vec2 glyph_offset;
+layout(location = 3) in vec2 glyph_size;
+layout(location = 4) in vec4 glyph_region;
+
+layout(location = 0) out vec2 tex_pos;
+layout(location = 1) out vec4 tint;
+
+layout(set = 0, binding = 0) uniform View {
+    float z;
+    mat3 transform;
+    mat3 camera_transform;
+    mat4 camera_proj;
+};
+
+void main(void) {
+        vec2 local = glyph_offset + uv * glyph_size;
+        vec2 pos = (inverse(camera_transform) * transform * vec3(local, 1.0)).xy;
+
+        gl_Position = camera_proj * vec4(vec3(pos, z), 1.0);
+
+    	tex_pos = glyph_region.xy + uv * glyph_region.zw;
+    	tint = vec4(1.0);
+}
+        ",
+}
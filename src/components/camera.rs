@@ -1,12 +1,35 @@
-use crate::nalgebra::{Matrix4, Orthographic3, Vector2};
+use crate::{
+    assets::{shape::Bounds, RenderTarget},
+    components::Trans,
+    nalgebra::{Matrix4, Orthographic3, Vector2},
+};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
+const MIN_ZOOM: f32 = 0.001;
+const MAX_ZOOM: f32 = 1000.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScaleMode {
+    #[default]
+    Stretch,
+    FixedWidth,
+    FixedHeight,
+    Fit,
+    Fill,
+    PixelPerfect,
+}
+
 #[derive(Clone)]
 pub struct Camera {
     dimensions: Vector2<f32>,
     end: u32,
+    scale_mode: ScaleMode,
+    zoom: f32,
+    viewport: Vector2<f32>,
+    view: Vector2<f32>,
     proj: Matrix4<f32>,
+    target: Option<RenderTarget>,
 }
 
 impl Camera {
@@ -14,7 +37,12 @@ impl Camera {
         Arc::new(RwLock::new(Self {
             dimensions,
             end,
+            scale_mode: ScaleMode::default(),
+            zoom: 1.0,
+            viewport: dimensions,
+            view: dimensions,
             proj: Self::calculate_proj(dimensions, end),
+            target: None,
         }))
     }
 
@@ -38,12 +66,101 @@ impl Camera {
         self.update_proj();
     }
 
+    pub fn scale_mode(&self) -> ScaleMode {
+        self.scale_mode
+    }
+
+    pub fn set_scale_mode(&mut self, scale_mode: ScaleMode) {
+        self.scale_mode = scale_mode;
+
+        self.update_proj();
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = if zoom.is_nan() {
+            MIN_ZOOM
+        } else {
+            zoom.clamp(MIN_ZOOM, MAX_ZOOM)
+        };
+
+        self.update_proj();
+    }
+
+    pub fn viewport(&self) -> Vector2<f32> {
+        self.viewport
+    }
+
+    pub fn set_viewport(&mut self, viewport: Vector2<f32>) {
+        if self.viewport != viewport {
+            self.viewport = viewport;
+
+            self.update_proj();
+        }
+    }
+
+    pub fn view(&self) -> Vector2<f32> {
+        self.view
+    }
+
     pub fn proj(&self) -> Matrix4<f32> {
         self.proj
     }
 
+    pub fn target(&self) -> Option<&RenderTarget> {
+        self.target.as_ref()
+    }
+
+    pub fn set_target(&mut self, target: Option<RenderTarget>) {
+        self.target = target;
+    }
+
+    pub fn bounds(&self, trans: &Trans) -> Bounds {
+        let half = self.view / 2.0;
+
+        Bounds::new(-half, half).transform(&trans.matrix())
+    }
+
+    pub fn screen_to_world(&self, trans: &Trans, screen: Vector2<f32>) -> Vector2<f32> {
+        let ndc = screen.component_div(&self.viewport) * 2.0 - Vector2::new(1.0, 1.0);
+        let view = ndc.component_mul(&self.view) / 2.0;
+
+        (trans.matrix() * view.push(1.0)).xy()
+    }
+
+    pub fn world_to_screen(&self, trans: &Trans, world: Vector2<f32>) -> Option<Vector2<f32>> {
+        let view = (trans.matrix().try_inverse()? * world.push(1.0)).xy();
+        let ndc = view.component_div(&self.view) * 2.0;
+
+        Some((ndc + Vector2::new(1.0, 1.0)).component_mul(&self.viewport) / 2.0)
+    }
+
     fn update_proj(&mut self) {
-        self.proj = Self::calculate_proj(self.dimensions, self.end);
+        self.view =
+            Self::calculate_view(self.dimensions, self.viewport, self.scale_mode, self.zoom);
+        self.proj = Self::calculate_proj(self.view, self.end);
+    }
+
+    fn calculate_view(
+        dimensions: Vector2<f32>,
+        viewport: Vector2<f32>,
+        scale_mode: ScaleMode,
+        zoom: f32,
+    ) -> Vector2<f32> {
+        let scale = viewport.component_div(&dimensions);
+        let view = match scale_mode {
+            ScaleMode::Stretch => dimensions,
+            ScaleMode::FixedWidth => Vector2::new(dimensions.x, viewport.y / scale.x),
+            ScaleMode::FixedHeight => Vector2::new(viewport.x / scale.y, dimensions.y),
+            ScaleMode::Fit => viewport / scale.min(),
+            ScaleMode::Fill => viewport / scale.max(),
+            ScaleMode::PixelPerfect => viewport / scale.min().floor().max(1.0),
+        };
+
+        view / zoom
     }
 
     fn calculate_proj(v: Vector2<f32>, end: u32) -> Matrix4<f32> {
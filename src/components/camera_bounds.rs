@@ -0,0 +1,34 @@
+use nalgebra::Vector2;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CameraBounds {
+    pub min: Vector2<f32>,
+    pub max: Vector2<f32>,
+}
+
+impl CameraBounds {
+    pub fn new(min: Vector2<f32>, max: Vector2<f32>) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self { min, max }))
+    }
+
+    pub fn clamp(&self, position: Vector2<f32>, view: Vector2<f32>) -> Vector2<f32> {
+        let half = view / 2.0;
+        let min = self.min + half;
+        let max = self.max - half;
+
+        Vector2::new(
+            Self::clamp_axis(position.x, min.x, max.x),
+            Self::clamp_axis(position.y, min.y, max.y),
+        )
+    }
+
+    fn clamp_axis(v: f32, min: f32, max: f32) -> f32 {
+        if min > max {
+            (min + max) / 2.0
+        } else {
+            v.clamp(min, max)
+        }
+    }
+}
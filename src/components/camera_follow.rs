@@ -0,0 +1,42 @@
+use crate::Id;
+use nalgebra::Vector2;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CameraFollow {
+    pub target: Id,
+    pub offset: Vector2<f32>,
+    pub damping: f32,
+    pub dead_zone: Vector2<f32>,
+}
+
+impl CameraFollow {
+    pub fn new(
+        target: Id,
+        offset: Vector2<f32>,
+        damping: f32,
+        dead_zone: Vector2<f32>,
+    ) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            target,
+            offset,
+            damping,
+            dead_zone,
+        }))
+    }
+
+    pub fn step(&self, position: Vector2<f32>, target: Vector2<f32>, delta: f32) -> Vector2<f32> {
+        let diff = target + self.offset - position;
+        let diff = diff.zip_map(&(self.dead_zone / 2.0), |d, z| {
+            d.signum() * (d.abs() - z).max(0.0)
+        });
+        let factor = if self.damping > 0.0 {
+            1.0 - (-self.damping * delta).exp()
+        } else {
+            1.0
+        };
+
+        position + diff * factor
+    }
+}
@@ -0,0 +1,67 @@
+use nalgebra::Vector2;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CameraShake {
+    pub trauma: f32,
+    pub decay: f32,
+    pub max_offset: Vector2<f32>,
+    pub max_angle: f32,
+    pub frequency: f32,
+    time: f32,
+    offset: Vector2<f32>,
+    angle: f32,
+}
+
+impl CameraShake {
+    pub fn new(
+        decay: f32,
+        max_offset: Vector2<f32>,
+        max_angle: f32,
+        frequency: f32,
+    ) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            trauma: 0.0,
+            decay,
+            max_offset,
+            max_angle,
+            frequency,
+            time: 0.0,
+            offset: Vector2::zeros(),
+            angle: 0.0,
+        }))
+    }
+
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0.0, 1.0);
+    }
+
+    pub fn offset(&self) -> Vector2<f32> {
+        self.offset
+    }
+
+    pub fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    pub fn step(&mut self, delta: f32) {
+        self.time += delta * self.frequency;
+        self.trauma = (self.trauma - self.decay * delta).max(0.0);
+
+        let shake = self.trauma * self.trauma;
+
+        self.offset = Vector2::new(
+            self.max_offset.x * shake * Self::noise(self.time, 0.0),
+            self.max_offset.y * shake * Self::noise(self.time, 17.0),
+        );
+        self.angle = self.max_angle * shake * Self::noise(self.time, 43.0);
+    }
+
+    fn noise(t: f32, seed: f32) -> f32 {
+        ((t + seed).sin()
+            + (t * 2.3 + seed * 1.7).sin() * 0.5
+            + (t * 4.1 + seed * 0.3).sin() * 0.25)
+            / 1.75
+    }
+}
@@ -0,0 +1,18 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec4 vertex_color;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 1, binding = 0) uniform Color {
+    vec4 color;
+};
+
+void main(void) {
+	frag_color = vertex_color * color;
+}
+        ",
+}
@@ -0,0 +1,117 @@
+use super::{fragment, vertex, Drawable, Mesh2dEntity};
+use crate::{
+    components::{Camera, Trans},
+    world::{renderer_manager::Draw, World},
+    Context, Id,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::{
+    buffer::{
+        allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo},
+        BufferUsage,
+    },
+    descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet},
+    memory::allocator::MemoryTypeFilter,
+    padded::Padded,
+    pipeline::{Pipeline, PipelineBindPoint},
+};
+
+pub struct Mesh2dDrawable;
+
+impl Mesh2dDrawable {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+impl Drawable<Mesh2dEntity> for Mesh2dDrawable {
+    fn draw(
+        self: Arc<Self>,
+        (_, m, t): Mesh2dEntity,
+        (_, c, ct): (Id, Arc<RwLock<Camera>>, Arc<RwLock<Trans>>),
+        (_, builder, recreate_swapchain, _): &mut Draw,
+        context: Arc<RwLock<Context>>,
+        _: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        let context = context.read();
+        let c = c.read();
+        let ct = ct.read();
+
+        if *recreate_swapchain || m.read().is_pipeline_outdated() {
+            let mut m = m.write();
+
+            m.recreate_pipeline(&context)?;
+        }
+
+        let m = m.read();
+        let t = t.read();
+
+        builder.bind_pipeline_graphics(m.pipeline.clone())?;
+
+        let uniform_allocator = SubbufferAllocator::new(
+            context.memory_allocator.clone(),
+            SubbufferAllocatorCreateInfo {
+                buffer_usage: BufferUsage::UNIFORM_BUFFER,
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+        );
+        let layouts = m.pipeline.layout().set_layouts();
+        let view = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = vertex::View {
+                z: Padded(-(m.layer as f32)),
+                transform: <[[f32; 3]; 3]>::from(t.matrix()).map(Padded),
+                camera_transform: <[[f32; 3]; 3]>::from(ct.matrix()).map(Padded),
+                camera_proj: c.proj().into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[0].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+        let color = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = fragment::Color {
+                color: m.color.into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[1].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+
+        builder
+            .bind_descriptor_sets(
+                PipelineBindPoint::Graphics,
+                m.pipeline.layout().clone(),
+                0,
+                vec![view, color],
+            )?
+            .bind_vertex_buffers(0, m.mesh.vertices.clone())?;
+
+        if let Some(indices) = &m.mesh.indices {
+            builder.bind_index_buffer(indices.clone())?.draw_indexed(
+                indices.len() as u32,
+                1,
+                0,
+                0,
+                0,
+            )?;
+        } else {
+            builder.draw(m.mesh.vertices.len() as u32, 1, 0, 0)?;
+        }
+
+        Ok(())
+    }
+}
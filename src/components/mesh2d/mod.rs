@@ -0,0 +1,142 @@
+pub mod fragment;
+pub mod mesh2d_drawable;
+pub mod vertex;
+
+pub use mesh2d_drawable::Mesh2dDrawable;
+
+use crate::{
+    assets::{mesh::ColorVertex2, Mesh},
+    components::{sprite::BlendMode, Trans},
+    Context, Drawable, Id,
+};
+use nalgebra::Vector4;
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::{
+    pipeline::{
+        graphics::{
+            color_blend::{ColorBlendAttachmentState, ColorBlendState},
+            depth_stencil::{CompareOp, DepthState, DepthStencilState},
+            input_assembly::{InputAssemblyState, PrimitiveTopology},
+            multisample::MultisampleState,
+            rasterization::RasterizationState,
+            vertex_input::{Vertex, VertexDefinition},
+            viewport::ViewportState,
+            GraphicsPipelineCreateInfo,
+        },
+        layout::PipelineDescriptorSetLayoutCreateInfo,
+        DynamicState, GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo,
+    },
+    render_pass::Subpass,
+};
+
+pub type Mesh2dEntity = (Id, Arc<RwLock<Mesh2d>>, Arc<RwLock<Trans>>);
+
+#[derive(Clone)]
+pub struct Mesh2d {
+    pub mesh: Mesh,
+    pub color: Vector4<f32>,
+    pub layer: u32,
+    pub blend_mode: BlendMode,
+    pub sort_key: Option<f32>,
+    pub drawable: Arc<dyn Drawable<Mesh2dEntity>>,
+    pub pipeline: Arc<GraphicsPipeline>,
+}
+
+impl Mesh2d {
+    pub fn new(context: &Context, mesh: Mesh, layer: u32) -> anyhow::Result<Arc<RwLock<Self>>> {
+        Ok(Arc::new(RwLock::new(Self {
+            color: Vector4::repeat(1.0),
+            layer,
+            blend_mode: BlendMode::default(),
+            sort_key: None,
+            pipeline: Self::pipeline(context, BlendMode::default(), mesh.topology)?,
+            mesh,
+            drawable: Mesh2dDrawable::new(),
+        })))
+    }
+
+    pub fn recreate_pipeline(&mut self, context: &Context) -> anyhow::Result<()> {
+        self.pipeline = Self::pipeline(context, self.blend_mode, self.mesh.topology)?;
+
+        Ok(())
+    }
+
+    pub fn is_pipeline_outdated(&self) -> bool {
+        self.pipeline.input_assembly_state().topology != self.mesh.topology
+    }
+
+    pub fn set_blend_mode(
+        &mut self,
+        context: &Context,
+        blend_mode: BlendMode,
+    ) -> anyhow::Result<()> {
+        self.blend_mode = blend_mode;
+
+        self.recreate_pipeline(context)
+    }
+
+    pub fn pipeline(
+        context: &Context,
+        blend_mode: BlendMode,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        context.pipeline(("mesh2d", blend_mode, topology), || {
+            Self::create_pipeline(context, blend_mode, topology)
+        })
+    }
+
+    fn create_pipeline(
+        context: &Context,
+        blend_mode: BlendMode,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        let vertex = context.shader("mesh2d::vertex", vertex::load)?;
+        let fragment = context.shader("mesh2d::fragment", fragment::load)?;
+        let vertex_input_state =
+            ColorVertex2::per_vertex().definition(&vertex.info().input_interface)?;
+        let stages = [
+            PipelineShaderStageCreateInfo::new(vertex),
+            PipelineShaderStageCreateInfo::new(fragment),
+        ];
+        let layout = PipelineLayout::new(
+            context.device.clone(),
+            PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+                .into_pipeline_layout_create_info(context.device.clone())?,
+        )?;
+        let subpass = Subpass::from(context.render_pass.clone(), 0).unwrap();
+
+        Ok(GraphicsPipeline::new(
+            context.device.clone(),
+            None,
+            GraphicsPipelineCreateInfo {
+                stages: stages.into_iter().collect(),
+                vertex_input_state: Some(vertex_input_state),
+                input_assembly_state: Some(InputAssemblyState {
+                    topology,
+                    ..Default::default()
+                }),
+                viewport_state: Some(ViewportState::default()),
+                rasterization_state: Some(RasterizationState::default()),
+                depth_stencil_state: Some(DepthStencilState {
+                    depth: Some(DepthState {
+                        write_enable: blend_mode.is_opaque(),
+                        compare_op: CompareOp::LessOrEqual,
+                    }),
+                    ..Default::default()
+                }),
+                multisample_state: Some(MultisampleState::default()),
+                color_blend_state: Some(ColorBlendState::with_attachment_states(
+                    subpass.num_color_attachments(),
+                    ColorBlendAttachmentState {
+                        blend: blend_mode.attachment_blend(),
+                        ..Default::default()
+                    },
+                )),
+                dynamic_state: [DynamicState::Viewport].into_iter().collect(),
+                subpass: Some(subpass.into()),
+                ..GraphicsPipelineCreateInfo::layout(layout)
+            },
+        )?)
+    }
+}
@@ -0,0 +1,26 @@
+vulkano_shaders::shader! {
+    ty: "vertex",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 position;
+layout(location = 1) in vec4 color;
+
+layout(location = 0) out vec4 vertex_color;
+
+layout(set = 0, binding = 0) uniform View {
+    float z;
+    mat3 transform;
+    mat3 camera_transform;
+    mat4 camera_proj;
+};
+
+void main(void) {
+        vec2 pos = (inverse(camera_transform) * transform * vec3(position, 1.0)).xy;
+
+        gl_Position = camera_proj * vec4(vec3(pos, z), 1.0);
+
+    	vertex_color = color;
+}
+        ",
+}
@@ -1,9 +1,31 @@
+pub mod animator;
+pub mod bitmap_text;
 pub mod camera;
+pub mod camera_bounds;
+pub mod camera_follow;
+pub mod camera_shake;
+pub mod mesh2d;
+pub mod particle_emitter;
+pub mod properties;
 pub mod sprite;
 pub mod tag;
+pub mod text;
+pub mod tilemap;
 pub mod trans;
+pub mod ui;
 
+pub use animator::Animator;
+pub use bitmap_text::BitmapText;
 pub use camera::Camera;
+pub use camera_bounds::CameraBounds;
+pub use camera_follow::CameraFollow;
+pub use camera_shake::CameraShake;
+pub use mesh2d::Mesh2d;
+pub use particle_emitter::ParticleEmitter;
+pub use properties::{Properties, TileProperties};
 pub use sprite::Sprite;
 pub use tag::Tag;
+pub use text::Text;
+pub use tilemap::Tilemap;
 pub use trans::Trans;
+pub use ui::UiNode;
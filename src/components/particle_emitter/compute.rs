@@ -0,0 +1,91 @@
+vulkano_shaders::shader! {
+    ty: "compute",
+    src: r"
+#version 450
+
+layout(local_size_x = 64) in;
+
+struct Particle {
+    vec4 motion;
+    vec4 life;
+    vec4 color;
+    vec4 scale;
+};
+
+layout(set = 0, binding = 0) buffer Particles {
+    Particle particles[];
+};
+
+layout(set = 0, binding = 1) uniform Params {
+    vec2 origin;
+    vec2 gravity;
+    vec2 speed;
+    vec2 angle;
+    vec2 lifetime;
+    vec2 size;
+    vec2 spin;
+    float delta;
+    float seed;
+    uint spawn_start;
+    uint spawn_count;
+    uint capacity;
+    vec4 colors[8];
+    vec4 sizes[2];
+};
+
+float random(uint index, uint salt) {
+        uint h = index * 747796405u + salt * 2891336453u + floatBitsToUint(seed);
+
+        h = ((h >> ((h >> 28u) + 4u)) ^ h) * 277803737u;
+        h = (h >> 22u) ^ h;
+
+        return float(h) / 4294967295.0;
+}
+
+float sample_size(float t) {
+        float x = clamp(t, 0.0, 1.0) * 7.0;
+        int i = min(int(x), 6);
+
+        return mix(sizes[i / 4][i % 4], sizes[(i + 1) / 4][(i + 1) % 4], x - float(i));
+}
+
+vec4 sample_color(float t) {
+        float x = clamp(t, 0.0, 1.0) * 7.0;
+        int i = min(int(x), 6);
+
+        return mix(colors[i], colors[i + 1], x - float(i));
+}
+
+void main(void) {
+        uint i = gl_GlobalInvocationID.x;
+
+        if (i >= capacity) {
+                return;
+        }
+
+        Particle p = particles[i];
+
+        if ((i + capacity - spawn_start) % capacity < spawn_count) {
+                float a = mix(angle.x, angle.y, random(i, 0u));
+                float s = mix(speed.x, speed.y, random(i, 1u));
+
+                p.motion = vec4(origin, cos(a) * s, sin(a) * s);
+                p.life = vec4(0.0, mix(lifetime.x, lifetime.y, random(i, 2u)), 0.0, mix(spin.x, spin.y, random(i, 3u)));
+                p.scale = vec4(0.0, mix(size.x, size.y, random(i, 4u)), 0.0, 0.0);
+        } else {
+                p.life.x += delta;
+                p.life.z += p.life.w * delta;
+                p.motion.zw += gravity * delta;
+                p.motion.xy += p.motion.zw * delta;
+        }
+
+        float t = p.life.y > 0.0 ? p.life.x / p.life.y : 1.0;
+        bool alive = t < 1.0;
+
+        p.color = alive ? sample_color(t) : vec4(0.0);
+        p.scale.x = alive ? p.scale.y * sample_size(t) : 0.0;
+
+        particles[i] = p;
+}
+        ",
+}
@@ -0,0 +1,40 @@
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Curve<T> {
+    pub keys: Vec<(f32, T)>,
+}
+
+impl<T> Curve<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    pub fn new(mut keys: Vec<(f32, T)>) -> Self {
+        keys.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Self { keys }
+    }
+
+    pub fn constant(value: T) -> Self {
+        Self::new(vec![(0.0, value)])
+    }
+
+    pub fn linear(from: T, to: T) -> Self {
+        Self::new(vec![(0.0, from), (1.0, to)])
+    }
+
+    pub fn sample(&self, t: f32) -> Option<T> {
+        let next = self.keys.iter().position(|(k, _)| *k > t);
+
+        match next {
+            Some(0) => self.keys.first().map(|(_, v)| *v),
+            Some(i) => {
+                let (t0, v0) = self.keys[i - 1];
+                let (t1, v1) = self.keys[i];
+
+                Some(v0 + (v1 - v0) * ((t - t0) / (t1 - t0)))
+            }
+            None => self.keys.last().map(|(_, v)| *v),
+        }
+    }
+}
@@ -0,0 +1,539 @@
+pub mod compute;
+pub mod curve;
+pub mod particle_drawable;
+pub mod particle_instance;
+pub mod vertex;
+
+pub use curve::Curve;
+pub use particle_drawable::ParticleDrawable;
+pub use particle_instance::ParticleInstance;
+
+use crate::{
+    assets::{texture_atlas::Region, Shape, Texture, TextureAtlas},
+    components::{
+        sprite::{fragment, BlendMode},
+        Sprite, Trans,
+    },
+    Context, Drawable, Id,
+};
+use nalgebra::{Rotation2, Vector2, Vector4};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    ops::Range,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use vulkano::{
+    buffer::{
+        allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo},
+        BufferUsage, Subbuffer,
+    },
+    descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet},
+    memory::allocator::MemoryTypeFilter,
+    padded::Padded,
+    pipeline::{
+        compute::ComputePipelineCreateInfo,
+        graphics::{input_assembly::PrimitiveTopology, vertex_input::Vertex},
+        layout::PipelineDescriptorSetLayoutCreateInfo,
+        ComputePipeline, GraphicsPipeline, Pipeline, PipelineBindPoint, PipelineLayout,
+        PipelineShaderStageCreateInfo,
+    },
+    DeviceSize,
+};
+
+pub type ParticleEmitterEntity = (Id, Arc<RwLock<ParticleEmitter>>, Arc<RwLock<Trans>>);
+
+const WORKGROUP_SIZE: u32 = 64;
+const CURVE_SAMPLES: usize = 8;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ParticleBackend {
+    #[default]
+    Cpu,
+    Gpu,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Burst {
+    pub time: Duration,
+    pub count: u32,
+}
+
+impl Burst {
+    pub fn new(time: Duration, count: u32) -> Self {
+        Self { time, count }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Particle {
+    pub position: Vector2<f32>,
+    pub velocity: Vector2<f32>,
+    pub rotation: f32,
+    pub spin: f32,
+    pub size: f32,
+    pub age: f32,
+    pub lifetime: f32,
+}
+
+struct GpuParticles {
+    buffer: Subbuffer<[ParticleInstance]>,
+    pipeline: Arc<ComputePipeline>,
+    next: u32,
+}
+
+pub struct ParticleEmitter {
+    pub rate: f32,
+    pub bursts: Vec<Burst>,
+    pub duration: Duration,
+    pub looping: bool,
+    pub emitting: bool,
+    pub lifetime: Range<f32>,
+    pub speed: Range<f32>,
+    pub angle: Range<f32>,
+    pub spin: Range<f32>,
+    pub size: Range<f32>,
+    pub gravity: Vector2<f32>,
+    pub size_curve: Curve<f32>,
+    pub color_curve: Curve<Vector4<f32>>,
+    pub texture: Texture,
+    pub region: Region,
+    pub color: Vector4<f32>,
+    pub layer: u32,
+    pub sort_key: Option<f32>,
+    pub shape: Shape,
+    max_particles: usize,
+    blend_mode: BlendMode,
+    backend: ParticleBackend,
+    particles: Vec<Particle>,
+    gpu: Option<GpuParticles>,
+    instance_allocator: Mutex<SubbufferAllocator>,
+    elapsed: Duration,
+    accumulator: f32,
+    pending: u32,
+    seed: u32,
+    pub drawable: Arc<dyn Drawable<ParticleEmitterEntity>>,
+    pub pipeline: Arc<GraphicsPipeline>,
+}
+
+impl ParticleEmitter {
+    pub fn new(
+        context: &Context,
+        texture: Texture,
+        max_particles: usize,
+        layer: u32,
+    ) -> anyhow::Result<Arc<RwLock<Self>>> {
+        let shape = Shape::rect(context, Vector2::new(1.0, 1.0))?;
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.subsec_nanos());
+
+        Ok(Arc::new(RwLock::new(Self {
+            rate: 10.0,
+            bursts: Vec::new(),
+            duration: Duration::from_secs(1),
+            looping: true,
+            emitting: true,
+            lifetime: 1.0..1.0,
+            speed: 50.0..100.0,
+            angle: 0.0..std::f32::consts::TAU,
+            spin: 0.0..0.0,
+            size: 8.0..8.0,
+            gravity: Vector2::zeros(),
+            size_curve: Curve::constant(1.0),
+            color_curve: Curve::constant(Vector4::new(1.0, 1.0, 1.0, 1.0)),
+            texture,
+            region: Region::default(),
+            color: Vector4::new(1.0, 1.0, 1.0, 1.0),
+            layer,
+            sort_key: None,
+            pipeline: Self::pipeline(context, BlendMode::default(), shape.topology)?,
+            shape,
+            max_particles,
+            blend_mode: BlendMode::default(),
+            backend: ParticleBackend::default(),
+            particles: Vec::with_capacity(max_particles),
+            gpu: None,
+            instance_allocator: Mutex::new(SubbufferAllocator::new(
+                context.memory_allocator.clone(),
+                SubbufferAllocatorCreateInfo {
+                    buffer_usage: BufferUsage::VERTEX_BUFFER,
+                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                        | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                    ..Default::default()
+                },
+            )),
+            elapsed: Duration::ZERO,
+            accumulator: 0.0,
+            pending: 0,
+            seed: seed | 1,
+            drawable: ParticleDrawable::new(),
+        })))
+    }
+
+    pub fn set_frame(&mut self, atlas: &TextureAtlas, frame: usize) {
+        if let Some(region) = atlas.frame(frame) {
+            self.texture = atlas.texture.clone();
+            self.region = region;
+        }
+    }
+
+    pub fn max_particles(&self) -> usize {
+        self.max_particles
+    }
+
+    pub fn set_max_particles(
+        &mut self,
+        context: &Context,
+        max_particles: usize,
+    ) -> anyhow::Result<()> {
+        self.max_particles = max_particles;
+        self.particles.truncate(max_particles);
+
+        self.set_backend(context, self.backend)
+    }
+
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    pub fn set_blend_mode(
+        &mut self,
+        context: &Context,
+        blend_mode: BlendMode,
+    ) -> anyhow::Result<()> {
+        self.blend_mode = blend_mode;
+
+        self.recreate_pipeline(context)
+    }
+
+    pub fn backend(&self) -> ParticleBackend {
+        self.backend
+    }
+
+    pub fn set_backend(
+        &mut self,
+        context: &Context,
+        backend: ParticleBackend,
+    ) -> anyhow::Result<()> {
+        self.backend = backend;
+        self.particles.clear();
+        self.gpu = match backend {
+            ParticleBackend::Cpu => None,
+            ParticleBackend::Gpu if self.max_particles == 0 => None,
+            ParticleBackend::Gpu => Some(GpuParticles {
+                buffer: Shape::buffer(
+                    context,
+                    BufferUsage::STORAGE_BUFFER | BufferUsage::VERTEX_BUFFER,
+                    &vec![ParticleInstance::default(); self.max_particles],
+                )?,
+                pipeline: Self::compute_pipeline(context)?,
+                next: 0,
+            }),
+        };
+
+        Ok(())
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn burst(&mut self, count: u32) {
+        self.pending += count;
+    }
+
+    pub fn restart(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.accumulator = 0.0;
+        self.emitting = true;
+    }
+
+    pub fn clear(&mut self, context: &Context) -> anyhow::Result<()> {
+        self.pending = 0;
+
+        self.set_backend(context, self.backend)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        !self.looping
+            && self.elapsed >= self.duration
+            && self.pending == 0
+            && match self.backend {
+                ParticleBackend::Cpu => self.particles.is_empty(),
+                ParticleBackend::Gpu => {
+                    self.elapsed.as_secs_f32() >= self.duration.as_secs_f32() + self.lifetime.end
+                }
+            }
+    }
+
+    pub fn update(&mut self, context: &Context, delta: Duration, t: &Trans) -> anyhow::Result<()> {
+        let count = self.emit(delta);
+
+        match self.backend {
+            ParticleBackend::Cpu => {
+                self.simulate(delta.as_secs_f32(), count, t);
+
+                Ok(())
+            }
+            ParticleBackend::Gpu => self.dispatch(context, delta.as_secs_f32(), count, t),
+        }
+    }
+
+    pub fn instances(&self) -> anyhow::Result<Option<(Subbuffer<[ParticleInstance]>, u32)>> {
+        if let Some(gpu) = &self.gpu {
+            return Ok(Some((gpu.buffer.clone(), gpu.buffer.len() as u32)));
+        }
+
+        if self.particles.is_empty() {
+            return Ok(None);
+        }
+
+        let buffer = self
+            .instance_allocator
+            .lock()
+            .allocate_slice(self.particles.len() as DeviceSize)?;
+
+        for (instance, p) in buffer.write()?.iter_mut().zip(&self.particles) {
+            *instance = self.instance(p);
+        }
+
+        Ok(Some((buffer, self.particles.len() as u32)))
+    }
+
+    pub fn recreate_pipeline(&mut self, context: &Context) -> anyhow::Result<()> {
+        self.pipeline = Self::pipeline(context, self.blend_mode, self.shape.topology)?;
+
+        Ok(())
+    }
+
+    pub fn is_pipeline_outdated(&self) -> bool {
+        self.pipeline.input_assembly_state().topology != self.shape.topology
+    }
+
+    pub fn pipeline(
+        context: &Context,
+        blend_mode: BlendMode,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        let vertex = context.shader("particle::vertex", vertex::load)?;
+        let fragment = context.shader("sprite::fragment", fragment::load)?;
+
+        context.shader_pipeline(
+            ("particle", blend_mode, topology),
+            &[&vertex.clone(), &fragment.clone()],
+            || {
+                Sprite::create_pipeline(
+                    context,
+                    vertex,
+                    fragment,
+                    blend_mode,
+                    topology,
+                    Some(ParticleInstance::per_instance()),
+                )
+            },
+        )
+    }
+
+    fn emit(&mut self, delta: Duration) -> u32 {
+        let from = self.elapsed.as_secs_f32();
+
+        self.elapsed += delta;
+
+        let to = self.elapsed.as_secs_f32();
+        let mut count = std::mem::take(&mut self.pending);
+
+        if !self.emitting {
+            return count;
+        }
+
+        if self.looping || self.duration.is_zero() || from < self.duration.as_secs_f32() {
+            self.accumulator += self.rate * delta.as_secs_f32();
+
+            let spawned = self.accumulator.floor();
+
+            self.accumulator -= spawned;
+            count += spawned as u32;
+        }
+
+        let period = self.duration.as_secs_f32();
+
+        for burst in &self.bursts {
+            let time = burst.time.as_secs_f32();
+            let fired = if self.looping && period > 0.0 {
+                ((to - time) / period).ceil() - ((from - time) / period).ceil().max(0.0)
+            } else {
+                (from <= time && time < to) as u32 as f32
+            };
+
+            count += burst.count * fired.max(0.0) as u32;
+        }
+
+        count
+    }
+
+    fn simulate(&mut self, delta: f32, count: u32, t: &Trans) {
+        for p in &mut self.particles {
+            p.age += delta;
+            p.velocity += self.gravity * delta;
+            p.position += p.velocity * delta;
+            p.rotation += p.spin * delta;
+        }
+
+        self.particles.retain(|p| p.age < p.lifetime);
+
+        let count = (count as usize).min(self.max_particles - self.particles.len());
+
+        for _ in 0..count {
+            let angle = t.rotation() + self.sample(self.angle.clone());
+            let speed = self.sample(self.speed.clone());
+            let particle = Particle {
+                position: t.position(),
+                velocity: Rotation2::new(angle) * Vector2::new(speed, 0.0),
+                rotation: 0.0,
+                spin: self.sample(self.spin.clone()),
+                size: self.sample(self.size.clone()),
+                age: 0.0,
+                lifetime: self.sample(self.lifetime.clone()),
+            };
+
+            self.particles.push(particle);
+        }
+    }
+
+    fn dispatch(
+        &mut self,
+        context: &Context,
+        delta: f32,
+        count: u32,
+        t: &Trans,
+    ) -> anyhow::Result<()> {
+        let seed = self.random();
+        let Some(gpu) = &mut self.gpu else {
+            return Ok(());
+        };
+        let capacity = gpu.buffer.len() as u32;
+        let count = count.min(capacity);
+        let uniform_allocator = SubbufferAllocator::new(
+            context.memory_allocator.clone(),
+            SubbufferAllocatorCreateInfo {
+                buffer_usage: BufferUsage::UNIFORM_BUFFER,
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+        );
+        let params = uniform_allocator.allocate_sized()?;
+        let range = |r: &Range<f32>| [r.start, r.end];
+        let samples = (0..CURVE_SAMPLES).map(|i| i as f32 / (CURVE_SAMPLES - 1) as f32);
+        let mut colors = [[1.0; 4]; CURVE_SAMPLES];
+        let mut sizes = [[1.0; 4]; CURVE_SAMPLES / 4];
+
+        for (i, s) in samples.enumerate() {
+            colors[i] = self
+                .color_curve
+                .sample(s)
+                .unwrap_or(Vector4::repeat(1.0))
+                .into();
+            sizes[i / 4][i % 4] = self.size_curve.sample(s).unwrap_or(1.0);
+        }
+
+        *params.write()? = compute::Params {
+            origin: t.position().into(),
+            gravity: self.gravity.into(),
+            speed: range(&self.speed),
+            angle: [
+                self.angle.start + t.rotation(),
+                self.angle.end + t.rotation(),
+            ],
+            lifetime: range(&self.lifetime),
+            size: range(&self.size),
+            spin: range(&self.spin),
+            delta,
+            seed,
+            spawn_start: gpu.next,
+            spawn_count: count,
+            capacity: Padded(capacity),
+            colors,
+            sizes,
+        };
+
+        let pipeline = gpu.pipeline.clone();
+        let set = PersistentDescriptorSet::new(
+            &context.descriptor_set_allocator,
+            pipeline.layout().set_layouts()[0].clone(),
+            [
+                WriteDescriptorSet::buffer(0, gpu.buffer.clone()),
+                WriteDescriptorSet::buffer(1, params),
+            ],
+            [],
+        )?;
+
+        gpu.next = (gpu.next + count) % capacity;
+
+        context.uploads.lock().push((), move |builder| {
+            builder
+                .bind_pipeline_compute(pipeline.clone())?
+                .bind_descriptor_sets(
+                    PipelineBindPoint::Compute,
+                    pipeline.layout().clone(),
+                    0,
+                    set.clone(),
+                )?
+                .dispatch([capacity.div_ceil(WORKGROUP_SIZE), 1, 1])?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    fn instance(&self, p: &Particle) -> ParticleInstance {
+        let t = if p.lifetime > 0.0 {
+            p.age / p.lifetime
+        } else {
+            1.0
+        };
+        let color = self.color_curve.sample(t).unwrap_or(Vector4::repeat(1.0));
+        let size = p.size * self.size_curve.sample(t).unwrap_or(1.0);
+
+        ParticleInstance {
+            particle_motion: [p.position.x, p.position.y, p.velocity.x, p.velocity.y],
+            particle_life: [p.age, p.lifetime, p.rotation, p.spin],
+            particle_color: color.into(),
+            particle_scale: [size, p.size, 0.0, 0.0],
+        }
+    }
+
+    fn random(&mut self) -> f32 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 17;
+        self.seed ^= self.seed << 5;
+
+        (self.seed >> 8) as f32 / (1 << 24) as f32
+    }
+
+    fn sample(&mut self, range: Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.random()
+    }
+
+    fn compute_pipeline(context: &Context) -> anyhow::Result<Arc<ComputePipeline>> {
+        let stage =
+            PipelineShaderStageCreateInfo::new(context.shader("particle::compute", compute::load)?);
+        let layout = PipelineLayout::new(
+            context.device.clone(),
+            PipelineDescriptorSetLayoutCreateInfo::from_stages([&stage])
+                .into_pipeline_layout_create_info(context.device.clone())?,
+        )?;
+
+        Ok(ComputePipeline::new(
+            context.device.clone(),
+            None,
+            ComputePipelineCreateInfo::stage_layout(stage, layout),
+        )?)
+    }
+}
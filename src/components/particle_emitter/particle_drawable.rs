@@ -0,0 +1,130 @@
+use super::{vertex, Drawable, ParticleEmitterEntity};
+use crate::{
+    components::{sprite::fragment, Camera, Trans},
+    world::{renderer_manager::Draw, World},
+    Context, Id,
+};
+use nalgebra::Matrix3;
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::{
+    buffer::{
+        allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo},
+        BufferUsage,
+    },
+    descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet},
+    memory::allocator::MemoryTypeFilter,
+    padded::Padded,
+    pipeline::{Pipeline, PipelineBindPoint},
+};
+
+pub struct ParticleDrawable;
+
+impl ParticleDrawable {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+impl Drawable<ParticleEmitterEntity> for ParticleDrawable {
+    fn draw(
+        self: Arc<Self>,
+        (_, p, _): ParticleEmitterEntity,
+        (_, c, ct): (Id, Arc<RwLock<Camera>>, Arc<RwLock<Trans>>),
+        (_, builder, recreate_swapchain, _): &mut Draw,
+        context: Arc<RwLock<Context>>,
+        _: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        let context = context.read();
+        let c = c.read();
+        let ct = ct.read();
+        let mut p = p.write();
+
+        if *recreate_swapchain || p.is_pipeline_outdated() {
+            p.recreate_pipeline(&context)?;
+        }
+
+        let Some((instances, count)) = p.instances()? else {
+            return Ok(());
+        };
+        let pipeline = p.pipeline.clone();
+
+        builder.bind_pipeline_graphics(pipeline.clone())?;
+
+        let uniform_allocator = SubbufferAllocator::new(
+            context.memory_allocator.clone(),
+            SubbufferAllocatorCreateInfo {
+                buffer_usage: BufferUsage::UNIFORM_BUFFER,
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+        );
+        let layouts = pipeline.layout().set_layouts();
+        let view = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = vertex::View {
+                z: Padded(-(p.layer as f32)),
+                transform: <[[f32; 3]; 3]>::from(Matrix3::<f32>::identity()).map(Padded),
+                camera_transform: <[[f32; 3]; 3]>::from(ct.matrix()).map(Padded),
+                camera_proj: c.proj().into(),
+                region: p.region.to_vector().into(),
+                rotated: p.region.rotated as u32,
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[0].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+        let texture = PersistentDescriptorSet::new(
+            &context.descriptor_set_allocator,
+            layouts[1].clone(),
+            [
+                WriteDescriptorSet::sampler(0, p.texture.sampler.clone()),
+                WriteDescriptorSet::image_view(1, p.texture.image.clone()),
+            ],
+            [],
+        )?;
+        let color = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = fragment::Color {
+                color: p.color.into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[2].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+
+        builder
+            .bind_descriptor_sets(
+                PipelineBindPoint::Graphics,
+                pipeline.layout().clone(),
+                0,
+                vec![view, texture, color],
+            )?
+            .bind_vertex_buffers(0, (p.shape.vertices.clone(), instances))?;
+
+        if let Some(indices) = &p.shape.indices {
+            builder.bind_index_buffer(indices.clone())?.draw_indexed(
+                indices.len() as u32,
+                count,
+                0,
+                0,
+                0,
+            )?;
+        } else {
+            builder.draw(p.shape.vertices.len() as u32, count, 0, 0)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use vulkano::{buffer::BufferContents, pipeline::graphics::vertex_input::Vertex};
+
+#[derive(BufferContents, Vertex, Copy, Clone, Default)]
+#[repr(C)]
+pub struct ParticleInstance {
+    #[format(R32G32B32A32_SFLOAT)]
+    pub particle_motion: [f32; 4],
+    #[format(R32G32B32A32_SFLOAT)]
+    pub particle_life: [f32; 4],
+    #[format(R32G32B32A32_SFLOAT)]
+    pub particle_color: [f32; 4],
+    #[format(R32G32B32A32_SFLOAT)]
+    pub particle_scale: [f32; 4],
+}
@@ -0,0 +1,39 @@
+vulkano_shaders::shader! {
+    ty: "vertex",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 position;
+layout(location = 1) in vec2 uv;
+layout(location = 2) in vec4 particle_motion;
+layout(location = 3) in vec4 particle_life;
+layout(location = 4) in vec4 particle_color;
+layout(location = 5) in vec4 particle_scale;
+
+layout(location = 0) out vec2 tex_pos;
+layout(location = 1) out vec4 tint;
+
+layout(set = 0, binding = 0) uniform View {
+    float z;
+    mat3 transform;
+    mat3 camera_transform;
+    mat4 camera_proj;
+    vec4 region;
+    uint rotated;
+};
+
+void main(void) {
+        float c = cos(particle_life.z);
+        float s = sin(particle_life.z);
+        vec2 local = mat2(c, s, -s, c) * position * particle_scale.x;
+        vec2 pos = (inverse(camera_transform) * transform * vec3(particle_motion.xy + local, 1.0)).xy;
+
+        gl_Position = camera_proj * vec4(vec3(pos, z), 1.0);
+
+    	vec2 st = rotated != 0 ? vec2(1.0 - uv.y, uv.x) : uv;
+
+    	tex_pos = region.xy + st * region.zw;
+    	tint = particle_color;
+}
+        ",
+}
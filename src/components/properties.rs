@@ -0,0 +1,79 @@
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Property {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Property {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(f) => Some(*f),
+            Self::Int(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Properties(pub HashMap<String, Property>);
+
+impl Properties {
+    pub fn new(properties: HashMap<String, Property>) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self(properties)))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Property> {
+        self.0.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TileProperties(pub HashMap<u32, (String, Properties)>);
+
+impl TileProperties {
+    pub fn new(tiles: HashMap<u32, (String, Properties)>) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self(tiles)))
+    }
+
+    pub fn class(&self, id: u32) -> Option<&str> {
+        self.0.get(&id).map(|(class, _)| class.as_str())
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Properties> {
+        self.0.get(&id).map(|(_, properties)| properties)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,38 @@
+use vulkano::pipeline::graphics::color_blend::{AttachmentBlend, BlendFactor, BlendOp};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    Opaque,
+    #[default]
+    Alpha,
+    PremultipliedAlpha,
+    Additive,
+    Multiply,
+    Screen,
+}
+
+impl BlendMode {
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, Self::Opaque)
+    }
+
+    pub fn attachment_blend(&self) -> Option<AttachmentBlend> {
+        let (src_color_blend_factor, dst_color_blend_factor) = match self {
+            Self::Opaque => return None,
+            Self::Alpha => (BlendFactor::SrcAlpha, BlendFactor::OneMinusSrcAlpha),
+            Self::PremultipliedAlpha => (BlendFactor::One, BlendFactor::OneMinusSrcAlpha),
+            Self::Additive => (BlendFactor::SrcAlpha, BlendFactor::One),
+            Self::Multiply => (BlendFactor::DstColor, BlendFactor::OneMinusSrcAlpha),
+            Self::Screen => (BlendFactor::One, BlendFactor::OneMinusSrcColor),
+        };
+
+        Some(AttachmentBlend {
+            src_color_blend_factor,
+            dst_color_blend_factor,
+            color_blend_op: BlendOp::Add,
+            src_alpha_blend_factor: BlendFactor::One,
+            dst_alpha_blend_factor: BlendFactor::OneMinusSrcAlpha,
+            alpha_blend_op: BlendOp::Add,
+        })
+    }
+}
@@ -4,6 +4,7 @@ vulkano_shaders::shader! {
 #version 450
 
 layout(location = 0) in vec2 tex_pos;
+layout(location = 1) in vec4 tint;
 
 layout(location = 0) out vec4 frag_color;
 
@@ -14,7 +15,7 @@ layout(set = 2, binding = 0) uniform Color {
 };
 
 void main(void) {
-	frag_color = texture(sampler2D(tex, s), tex_pos) * color;
+	frag_color = texture(sampler2D(tex, s), tex_pos) * tint * color;
 }
         ",
 }
@@ -1,34 +1,39 @@
+pub mod blend_mode;
 pub mod fragment;
+pub mod nine_slice;
 pub mod sprite_drawable;
 pub mod vertex;
 
+pub use blend_mode::BlendMode;
+pub use nine_slice::{NineSlice, SliceMode};
 pub use sprite_drawable::SpriteDrawable;
 
 use crate::{
-    assets::{shape::Vertex2, Shape, Texture},
+    assets::{shape::Vertex2, texture_atlas::Region, Material, Shape, Texture},
     components::Trans,
     Context, Drawable, Id,
 };
-use nalgebra::Vector4;
+use nalgebra::{Vector2, Vector4};
 use parking_lot::RwLock;
 use std::sync::Arc;
 use vulkano::{
     pipeline::{
         graphics::{
-            color_blend::{AttachmentBlend, ColorBlendAttachmentState, ColorBlendState},
+            color_blend::{ColorBlendAttachmentState, ColorBlendState},
             depth_stencil::{CompareOp, DepthState, DepthStencilState},
             input_assembly::{InputAssemblyState, PrimitiveTopology},
             multisample::MultisampleState,
             rasterization::RasterizationState,
-            vertex_input::{Vertex, VertexDefinition},
+            vertex_input::{Vertex, VertexBufferDescription, VertexDefinition},
             viewport::ViewportState,
             GraphicsPipelineCreateInfo,
         },
         layout::PipelineDescriptorSetLayoutCreateInfo,
-        GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo,
+        DynamicState, GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo,
     },
     render_pass::Subpass,
     shader::EntryPoint,
+    VulkanObject,
 };
 
 pub type SpriteEntity = (Id, Arc<RwLock<Sprite>>, Arc<RwLock<Trans>>);
@@ -38,8 +43,13 @@ pub type SpritePipeline = (Arc<GraphicsPipeline>, EntryPoint, EntryPoint);
 pub struct Sprite {
     pub shape: Shape,
     pub texture: Texture,
+    pub region: Region,
     pub color: Vector4<f32>,
     pub layer: u32,
+    pub blend_mode: BlendMode,
+    pub sort_key: Option<f32>,
+    pub material: Option<Material>,
+    pub nine_slice: Option<(NineSlice, Shape)>,
     pub drawable: Arc<dyn Drawable<SpriteEntity>>,
     pub pipeline: SpritePipeline,
 }
@@ -52,42 +62,148 @@ impl Sprite {
         color: Vector4<f32>,
         layer: u32,
     ) -> anyhow::Result<Arc<RwLock<Self>>> {
-        let vertex = vertex::load(context.device.clone())?
-            .entry_point("main")
-            .unwrap();
-        let fragment = fragment::load(context.device.clone())?
-            .entry_point("main")
-            .unwrap();
+        let vertex = context.shader("sprite::vertex", vertex::load)?;
+        let fragment = context.shader("sprite::fragment", fragment::load)?;
 
         Ok(Arc::new(RwLock::new(Self {
-            shape,
             texture,
+            region: Region::default(),
             color,
             layer,
+            blend_mode: BlendMode::default(),
+            sort_key: None,
+            material: None,
+            nine_slice: None,
             pipeline: (
-                Self::pipeline(context, vertex.clone(), fragment.clone())?,
+                Self::pipeline(
+                    context,
+                    vertex.clone(),
+                    fragment.clone(),
+                    BlendMode::default(),
+                    shape.topology,
+                )?,
                 vertex,
                 fragment,
             ),
+            shape,
             drawable: SpriteDrawable::new(),
         })))
     }
 
     pub fn recreate_pipeline(&mut self, context: &Context) -> anyhow::Result<()> {
+        if let Some(shader) = self.material.as_ref().and_then(|m| m.shader.get()) {
+            self.pipeline.2 = shader.entry_point;
+        }
+
         let (ref mut pipeline, ref vertex, ref fragment) = self.pipeline;
 
-        *pipeline = Self::pipeline(context, vertex.clone(), fragment.clone())?;
+        *pipeline = Self::pipeline(
+            context,
+            vertex.clone(),
+            fragment.clone(),
+            self.blend_mode,
+            self.shape.topology,
+        )?;
 
         Ok(())
     }
 
+    pub fn is_pipeline_outdated(&self) -> bool {
+        let (ref pipeline, _, ref fragment) = self.pipeline;
+
+        pipeline.input_assembly_state().topology != self.shape.topology
+            || self
+                .material
+                .as_ref()
+                .and_then(|m| m.shader.get())
+                .is_some_and(|s| s.entry_point.module().handle() != fragment.module().handle())
+    }
+
+    pub fn set_material(
+        &mut self,
+        context: &Context,
+        material: Option<Material>,
+    ) -> anyhow::Result<()> {
+        self.material = material;
+
+        if self.material.is_none() {
+            self.pipeline.2 = context.shader("sprite::fragment", fragment::load)?;
+        }
+
+        self.recreate_pipeline(context)
+    }
+
+    pub fn set_nine_slice(
+        &mut self,
+        context: &Context,
+        nine_slice: Option<NineSlice>,
+    ) -> anyhow::Result<()> {
+        self.nine_slice = match nine_slice {
+            Some(nine_slice) => {
+                let [width, height, _] = self.texture.image.image().extent();
+                let texels = self
+                    .region
+                    .frame_size()
+                    .component_mul(&Vector2::new(width as f32, height as f32));
+                let shape = nine_slice.shape(context, texels)?;
+                let original = match self.nine_slice.take() {
+                    Some((_, original)) => original,
+                    None => self.shape.clone(),
+                };
+
+                self.shape = shape;
+
+                Some((nine_slice, original))
+            }
+            None => {
+                if let Some((_, original)) = self.nine_slice.take() {
+                    self.shape = original;
+                }
+
+                None
+            }
+        };
+
+        self.recreate_pipeline(context)
+    }
+
+    pub fn set_blend_mode(
+        &mut self,
+        context: &Context,
+        blend_mode: BlendMode,
+    ) -> anyhow::Result<()> {
+        self.blend_mode = blend_mode;
+
+        self.recreate_pipeline(context)
+    }
+
     pub fn pipeline(
         context: &Context,
         vertex: EntryPoint,
         fragment: EntryPoint,
+        blend_mode: BlendMode,
+        topology: PrimitiveTopology,
     ) -> anyhow::Result<Arc<GraphicsPipeline>> {
-        let vertex_input_state =
-            Vertex2::per_vertex().definition(&vertex.info().input_interface)?;
+        context.shader_pipeline(
+            ("sprite", blend_mode, topology),
+            &[&vertex.clone(), &fragment.clone()],
+            || Self::create_pipeline(context, vertex, fragment, blend_mode, topology, None),
+        )
+    }
+
+    pub(crate) fn create_pipeline(
+        context: &Context,
+        vertex: EntryPoint,
+        fragment: EntryPoint,
+        blend_mode: BlendMode,
+        topology: PrimitiveTopology,
+        instance: Option<VertexBufferDescription>,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        let vertex_input_state = [Vertex2::per_vertex()]
+            .into_iter()
+            .chain(instance)
+            .collect::<Vec<_>>()
+            .definition(&vertex.info().input_interface)?;
         let stages = [
             PipelineShaderStageCreateInfo::new(vertex),
             PipelineShaderStageCreateInfo::new(fragment),
@@ -106,17 +222,14 @@ impl Sprite {
                 stages: stages.into_iter().collect(),
                 vertex_input_state: Some(vertex_input_state),
                 input_assembly_state: Some(InputAssemblyState {
-                    topology: PrimitiveTopology::TriangleFan,
-                    ..Default::default()
-                }),
-                viewport_state: Some(ViewportState {
-                    viewports: [context.viewport.clone()].into_iter().collect(),
+                    topology,
                     ..Default::default()
                 }),
+                viewport_state: Some(ViewportState::default()),
                 rasterization_state: Some(RasterizationState::default()),
                 depth_stencil_state: Some(DepthStencilState {
                     depth: Some(DepthState {
-                        write_enable: true,
+                        write_enable: blend_mode.is_opaque(),
                         compare_op: CompareOp::LessOrEqual,
                     }),
                     ..Default::default()
@@ -125,10 +238,11 @@ impl Sprite {
                 color_blend_state: Some(ColorBlendState::with_attachment_states(
                     subpass.num_color_attachments(),
                     ColorBlendAttachmentState {
-                        blend: Some(AttachmentBlend::alpha()),
+                        blend: blend_mode.attachment_blend(),
                         ..Default::default()
                     },
                 )),
+                dynamic_state: [DynamicState::Viewport].into_iter().collect(),
                 subpass: Some(subpass.into()),
                 ..GraphicsPipelineCreateInfo::layout(layout)
             },
@@ -0,0 +1,113 @@
+use crate::{
+    assets::{shape::Vertex2, Shape},
+    Context,
+};
+use nalgebra::{Vector2, Vector4};
+use vulkano::pipeline::graphics::input_assembly::PrimitiveTopology;
+
+const MAX_TILES: f32 = 64.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SliceMode {
+    #[default]
+    Stretch,
+    Tile,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NineSlice {
+    pub insets: Vector4<f32>,
+    pub size: Vector2<f32>,
+    pub scale: f32,
+    pub mode: SliceMode,
+}
+
+impl NineSlice {
+    pub fn new(insets: Vector4<f32>, size: Vector2<f32>) -> Self {
+        Self {
+            insets,
+            size,
+            scale: 1.0,
+            mode: SliceMode::default(),
+        }
+    }
+
+    pub fn shape(&self, context: &Context, texels: Vector2<f32>) -> anyhow::Result<Shape> {
+        anyhow::ensure!(
+            self.insets.iter().all(|i| *i >= 0.0),
+            "Nine-slice insets must not be negative"
+        );
+        anyhow::ensure!(
+            self.insets.x + self.insets.z <= texels.x && self.insets.y + self.insets.w <= texels.y,
+            "Nine-slice insets exceed the {}x{} texel region",
+            texels.x,
+            texels.y
+        );
+        anyhow::ensure!(self.scale > 0.0, "Nine-slice scale must be positive");
+
+        let xs = self.segments(self.size.x, texels.x, self.insets.x, self.insets.z);
+        let ys = self.segments(self.size.y, texels.y, self.insets.y, self.insets.w);
+        let origin = -self.size / 2.0;
+        let mut vertices = Vec::with_capacity(xs.len() * ys.len() * 4);
+        let mut indices = Vec::with_capacity(xs.len() * ys.len() * 6);
+
+        for &(x0, x1, u0, u1) in &xs {
+            for &(y0, y1, v0, v1) in &ys {
+                let start = vertices.len() as u32;
+
+                vertices.extend([
+                    Vertex2::new(origin + Vector2::new(x0, y0), Vector2::new(u0, v0)),
+                    Vertex2::new(origin + Vector2::new(x1, y0), Vector2::new(u1, v0)),
+                    Vertex2::new(origin + Vector2::new(x1, y1), Vector2::new(u1, v1)),
+                    Vertex2::new(origin + Vector2::new(x0, y1), Vector2::new(u0, v1)),
+                ]);
+                indices.extend([0, 1, 2, 0, 2, 3].map(|i| start + i));
+            }
+        }
+
+        Shape::indexed(
+            context,
+            &vertices,
+            &indices,
+            PrimitiveTopology::TriangleList,
+        )
+    }
+
+    fn segments(&self, size: f32, texels: f32, start: f32, end: f32) -> Vec<(f32, f32, f32, f32)> {
+        let (first, last) = {
+            let border = (start + end) * self.scale;
+            let fit = if border > size { size / border } else { 1.0 };
+
+            (start * self.scale * fit, end * self.scale * fit)
+        };
+        let (u0, u1) = (start / texels, 1.0 - end / texels);
+        let mut segments = vec![(0.0, first, 0.0, u0)];
+        let middle = size - last;
+
+        match self.mode {
+            SliceMode::Stretch => segments.push((first, middle, u0, u1)),
+            SliceMode::Tile => {
+                let tile = ((texels - start - end) * self.scale).max((middle - first) / MAX_TILES);
+                let mut position = first;
+
+                while tile > 0.0 && position < middle {
+                    let next = (position + tile).min(middle);
+
+                    segments.push((
+                        position,
+                        next,
+                        u0,
+                        u0 + (u1 - u0) * (next - position) / tile,
+                    ));
+
+                    position = next;
+                }
+            }
+        }
+
+        segments.push((middle, size, u1, 1.0));
+        segments.retain(|(p0, p1, ..)| p1 > p0);
+
+        segments
+    }
+}
@@ -15,6 +15,7 @@ use vulkano::{
     memory::allocator::MemoryTypeFilter,
     padded::Padded,
     pipeline::{Pipeline, PipelineBindPoint},
+    DeviceSize,
 };
 
 pub struct SpriteDrawable;
@@ -30,7 +31,7 @@ impl Drawable<SpriteEntity> for SpriteDrawable {
         self: Arc<Self>,
         (_, s, t): SpriteEntity,
         (_, c, ct): (Id, Arc<RwLock<Camera>>, Arc<RwLock<Trans>>),
-        (_, builder, recreate_swapchain): &mut Draw,
+        (_, builder, recreate_swapchain, _): &mut Draw,
         context: Arc<RwLock<Context>>,
         _: Arc<RwLock<World>>,
     ) -> anyhow::Result<()> {
@@ -38,7 +39,7 @@ impl Drawable<SpriteEntity> for SpriteDrawable {
         let c = c.read();
         let ct = ct.read();
 
-        if *recreate_swapchain {
+        if *recreate_swapchain || s.read().is_pipeline_outdated() {
             let mut s = s.write();
 
             s.recreate_pipeline(&context)?;
@@ -50,92 +51,132 @@ impl Drawable<SpriteEntity> for SpriteDrawable {
 
         builder.bind_pipeline_graphics(pipeline.clone())?;
 
-        let view = {
-            let layout = pipeline.layout().set_layouts().first().unwrap();
-            let subbuffer_allocator = SubbufferAllocator::new(
-                context.memory_allocator.clone(),
-                SubbufferAllocatorCreateInfo {
-                    buffer_usage: BufferUsage::UNIFORM_BUFFER,
-                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
-                        | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
-                    ..Default::default()
-                },
-            );
-            let subbuffer = subbuffer_allocator.allocate_sized()?;
+        let uniform_allocator = SubbufferAllocator::new(
+            context.memory_allocator.clone(),
+            SubbufferAllocatorCreateInfo {
+                buffer_usage: BufferUsage::UNIFORM_BUFFER,
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+        );
+        let layouts = pipeline.layout().set_layouts();
+        let mut sets = Vec::new();
+
+        if let Some(layout) = layouts.first() {
+            let subbuffer = uniform_allocator.allocate_sized()?;
 
             *subbuffer.write()? = vertex::View {
                 z: Padded(-(s.layer as f32)),
-                transform: <[[f32; 3]; 3]>::from(t.matrix()).map(Padded),
+                transform: <[[f32; 3]; 3]>::from(t.matrix() * s.region.trim(&s.shape.bounds))
+                    .map(Padded),
                 camera_transform: <[[f32; 3]; 3]>::from(ct.matrix()).map(Padded),
                 camera_proj: c.proj().into(),
+                region: s.region.to_vector().into(),
+                rotated: s.region.rotated as u32,
             };
 
-            PersistentDescriptorSet::new(
-                &context.descriptor_set_allocator,
-                layout.clone(),
-                [WriteDescriptorSet::buffer(0, subbuffer)],
-                [],
-            )?
-        };
-        let texture = {
-            let layout = pipeline.layout().set_layouts().get(1).unwrap();
-
-            PersistentDescriptorSet::new(
-                &context.descriptor_set_allocator,
-                layout.clone(),
-                [
-                    WriteDescriptorSet::sampler(0, s.texture.sampler.clone()),
-                    WriteDescriptorSet::image_view(1, s.texture.image.clone()),
-                ],
-                [],
-            )?
-        };
-        let color = {
-            let layout = pipeline.layout().set_layouts().get(2).unwrap();
-            let subbuffer_allocator = SubbufferAllocator::new(
-                context.memory_allocator.clone(),
-                SubbufferAllocatorCreateInfo {
-                    buffer_usage: BufferUsage::UNIFORM_BUFFER,
-                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
-                        | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
-                    ..Default::default()
-                },
-            );
-            let subbuffer = subbuffer_allocator.allocate_sized()?;
+            sets.push((
+                0,
+                PersistentDescriptorSet::new(
+                    &context.descriptor_set_allocator,
+                    layout.clone(),
+                    [WriteDescriptorSet::buffer(0, subbuffer)],
+                    [],
+                )?,
+            ));
+        }
+
+        if let Some(layout) = layouts.get(1).filter(|l| !l.bindings().is_empty()) {
+            sets.push((
+                1,
+                PersistentDescriptorSet::new(
+                    &context.descriptor_set_allocator,
+                    layout.clone(),
+                    [
+                        WriteDescriptorSet::sampler(0, s.texture.sampler.clone()),
+                        WriteDescriptorSet::image_view(1, s.texture.image.clone()),
+                    ],
+                    [],
+                )?,
+            ));
+        }
+
+        if let Some(layout) = layouts.get(2).filter(|l| !l.bindings().is_empty()) {
+            let subbuffer = uniform_allocator.allocate_sized()?;
 
             *subbuffer.write()? = fragment::Color {
                 color: s.color.into(),
             };
 
-            PersistentDescriptorSet::new(
-                &context.descriptor_set_allocator,
-                layout.clone(),
-                [WriteDescriptorSet::buffer(0, subbuffer)],
-                [],
-            )?
-        };
+            sets.push((
+                2,
+                PersistentDescriptorSet::new(
+                    &context.descriptor_set_allocator,
+                    layout.clone(),
+                    [WriteDescriptorSet::buffer(0, subbuffer)],
+                    [],
+                )?,
+            ));
+        }
 
-        builder
-            .bind_descriptor_sets(
-                PipelineBindPoint::Graphics,
-                pipeline.layout().clone(),
-                0,
-                view.clone(),
-            )?
-            .bind_descriptor_sets(
+        if let Some((layout, material)) = layouts
+            .get(3)
+            .filter(|l| !l.bindings().is_empty())
+            .zip(s.material.as_ref())
+        {
+            let mut writes = Vec::new();
+            let bytes = material.uniform_bytes();
+
+            if !bytes.is_empty() {
+                let subbuffer = uniform_allocator.allocate_slice(bytes.len() as DeviceSize)?;
+
+                subbuffer.write()?.copy_from_slice(&bytes);
+
+                writes.push(WriteDescriptorSet::buffer(0, subbuffer));
+            }
+
+            for (i, texture) in material.textures.iter().enumerate() {
+                writes.push(WriteDescriptorSet::image_view_sampler(
+                    i as u32 + 1,
+                    texture.image.clone(),
+                    texture.sampler.clone(),
+                ));
+            }
+
+            sets.push((
+                3,
+                PersistentDescriptorSet::new(
+                    &context.descriptor_set_allocator,
+                    layout.clone(),
+                    writes,
+                    [],
+                )?,
+            ));
+        }
+
+        for (index, set) in sets {
+            builder.bind_descriptor_sets(
                 PipelineBindPoint::Graphics,
                 pipeline.layout().clone(),
+                index,
+                set,
+            )?;
+        }
+
+        builder.bind_vertex_buffers(0, s.shape.vertices.clone())?;
+
+        if let Some(indices) = &s.shape.indices {
+            builder.bind_index_buffer(indices.clone())?.draw_indexed(
+                indices.len() as u32,
                 1,
-                texture.clone(),
-            )?
-            .bind_descriptor_sets(
-                PipelineBindPoint::Graphics,
-                pipeline.layout().clone(),
-                2,
-                color.clone(),
-            )?
-            .bind_vertex_buffers(0, s.shape.vertices.clone())?
-            .draw(s.shape.vertices.len() as u32, 1, 0, 0)?;
+                0,
+                0,
+                0,
+            )?;
+        } else {
+            builder.draw(s.shape.vertices.len() as u32, 1, 0, 0)?;
+        }
 
         Ok(())
     }
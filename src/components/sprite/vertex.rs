@@ -7,12 +7,15 @@ layout(location = 0) in vec2 position;
 layout(location = 1) in vec2 uv;
 
 layout(location = 0) out vec2 tex_pos;
+layout(location = 1) out vec4 tint;
 
 layout(set = 0, binding = 0) uniform View {
     float z;
     mat3 transform;
     mat3 camera_transform;
     mat4 camera_proj;
+    vec4 region;
+    uint rotated;
 };
 
 void main(void) {
@@ -20,7 +23,10 @@ void main(void) {
 
         gl_Position = camera_proj * vec4(vec3(pos, z), 1.0);
 
-    	tex_pos = uv;
+    	vec2 st = rotated != 0 ? vec2(1.0 - uv.y, uv.x) : uv;
+
+    	tex_pos = region.xy + st * region.zw;
+    	tint = vec4(1.0);
 }
         ",
 }
@@ -0,0 +1,296 @@
+use crate::{
+    assets::{
+        font::GlyphAtlas,
+        shape::{Bounds, Vertex2},
+        Font, Shape,
+    },
+    components::Sprite,
+    Context,
+};
+use ab_glyph::{Font as _, FontArc, GlyphId, PxScaleFont, ScaleFont};
+use nalgebra::{Vector2, Vector4};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::pipeline::graphics::input_assembly::PrimitiveTopology;
+
+const LAYOUT_ATTEMPTS: usize = 2;
+
+type Line = (Vec<(GlyphId, f32)>, f32);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+pub struct Text {
+    font: Font,
+    text: String,
+    size: f32,
+    align: TextAlign,
+    wrap: Option<f32>,
+    line_spacing: f32,
+    pub color: Vector4<f32>,
+    pub layer: u32,
+    pub sort_key: Option<f32>,
+    bounds: Bounds,
+    sprite: Option<Arc<RwLock<Sprite>>>,
+    generation: Option<u64>,
+}
+
+impl Text {
+    pub fn new(
+        font: Font,
+        text: impl Into<String>,
+        size: f32,
+        color: Vector4<f32>,
+        layer: u32,
+    ) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            font,
+            text: text.into(),
+            size,
+            align: TextAlign::default(),
+            wrap: None,
+            line_spacing: 1.0,
+            color,
+            layer,
+            sort_key: None,
+            bounds: Bounds::new(Vector2::zeros(), Vector2::zeros()),
+            sprite: None,
+            generation: None,
+        }))
+    }
+
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    pub fn set_font(&mut self, font: Font) {
+        self.font = font;
+        self.sprite = None;
+        self.generation = None;
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let text = text.into();
+
+        if self.text != text {
+            self.text = text;
+            self.generation = None;
+        }
+    }
+
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    pub fn set_size(&mut self, size: f32) {
+        self.size = size;
+        self.generation = None;
+    }
+
+    pub fn align(&self) -> TextAlign {
+        self.align
+    }
+
+    pub fn set_align(&mut self, align: TextAlign) {
+        self.align = align;
+        self.generation = None;
+    }
+
+    pub fn wrap(&self) -> Option<f32> {
+        self.wrap
+    }
+
+    pub fn set_wrap(&mut self, wrap: Option<f32>) {
+        self.wrap = wrap;
+        self.generation = None;
+    }
+
+    pub fn line_spacing(&self) -> f32 {
+        self.line_spacing
+    }
+
+    pub fn set_line_spacing(&mut self, line_spacing: f32) {
+        self.line_spacing = line_spacing;
+        self.generation = None;
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    pub fn sprite(&self) -> Option<&Arc<RwLock<Sprite>>> {
+        self.sprite.as_ref()
+    }
+
+    pub fn update(&mut self, context: &Context) -> anyhow::Result<()> {
+        let atlas = self.font.atlas.clone();
+        let mut atlas = atlas.lock();
+
+        if self.generation != Some(atlas.generation()) {
+            let (vertices, indices) = (0..LAYOUT_ATTEMPTS)
+                .find_map(|_| {
+                    let generation = atlas.generation();
+                    let quads = self.quads(&mut atlas);
+
+                    (atlas.generation() == generation).then_some(quads)
+                })
+                .ok_or_else(|| anyhow::anyhow!("Text does not fit in the glyph atlas"))??;
+
+            self.generation = Some(atlas.generation());
+            self.sprite = if vertices.is_empty() {
+                self.bounds = Bounds::new(Vector2::zeros(), Vector2::zeros());
+
+                None
+            } else {
+                let shape = Shape::indexed(
+                    context,
+                    &vertices,
+                    &indices,
+                    PrimitiveTopology::TriangleList,
+                )?;
+                let texture = atlas.texture(context)?;
+
+                self.bounds = shape.bounds;
+
+                match self.sprite.take() {
+                    Some(sprite) => {
+                        {
+                            let mut s = sprite.write();
+
+                            s.shape = shape;
+                            s.texture = texture;
+                        }
+
+                        Some(sprite)
+                    }
+                    None => Some(Sprite::new(
+                        context, shape, texture, self.color, self.layer,
+                    )?),
+                }
+            };
+        }
+
+        if let Some(sprite) = &self.sprite {
+            atlas.texture(context)?;
+
+            let mut s = sprite.write();
+
+            s.color = self.color;
+            s.layer = self.layer;
+            s.sort_key = self.sort_key;
+        }
+
+        Ok(())
+    }
+
+    fn quads(&self, atlas: &mut GlyphAtlas) -> anyhow::Result<(Vec<Vertex2>, Vec<u32>)> {
+        let scaled = self.font.font.as_scaled(self.size);
+        let line_height = (scaled.height() + scaled.line_gap()) * self.line_spacing;
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for (i, (glyphs, width)) in self.lines(&scaled).into_iter().enumerate() {
+            let x = match self.align {
+                TextAlign::Left => 0.0,
+                TextAlign::Center => -width / 2.0,
+                TextAlign::Right => -width,
+            };
+            let baseline = scaled.ascent() + i as f32 * line_height;
+
+            for (id, advance) in glyphs {
+                let Some(glyph) = atlas.glyph(&self.font.font, id, self.size)? else {
+                    continue;
+                };
+                let min = Vector2::new(x + advance, baseline) + glyph.offset;
+                let start = vertices.len() as u32;
+
+                vertices.extend(
+                    [
+                        Vector2::new(0.0, 0.0),
+                        Vector2::new(1.0, 0.0),
+                        Vector2::new(1.0, 1.0),
+                        Vector2::new(0.0, 1.0),
+                    ]
+                    .map(|c| {
+                        Vertex2::new(
+                            min + glyph.size.component_mul(&c),
+                            glyph.region.offset + glyph.region.size.component_mul(&c),
+                        )
+                    }),
+                );
+                indices.extend([0, 1, 2, 0, 2, 3].map(|i| start + i));
+            }
+        }
+
+        Ok((vertices, indices))
+    }
+
+    fn lines(&self, scaled: &PxScaleFont<&FontArc>) -> Vec<Line> {
+        let mut lines = Vec::new();
+
+        for paragraph in self.text.split('\n') {
+            let mut line = Vec::new();
+            let mut x = 0.0;
+            let mut width = 0.0;
+            let mut last = None;
+
+            for word in paragraph.split_inclusive(' ') {
+                let place = |mut x: f32, mut last: Option<GlyphId>| {
+                    let mut glyphs = Vec::new();
+                    let mut width = None;
+
+                    for c in word.chars() {
+                        let id = scaled.glyph_id(c);
+
+                        if let Some(last) = last {
+                            x += scaled.kern(last, id);
+                        }
+
+                        glyphs.push((id, x));
+
+                        x += scaled.h_advance(id);
+                        last = Some(id);
+
+                        if !c.is_whitespace() {
+                            width = Some(x);
+                        }
+                    }
+
+                    (glyphs, x, width, last)
+                };
+                let mut placed = place(x, last);
+
+                if self.wrap.zip(placed.2).is_some_and(|(w, e)| e > w) && !line.is_empty() {
+                    lines.push((std::mem::take(&mut line), width));
+
+                    placed = place(0.0, None);
+                    width = 0.0;
+                }
+
+                let (glyphs, end, end_width, end_last) = placed;
+
+                line.extend(glyphs);
+
+                x = end;
+                last = end_last;
+
+                if let Some(end_width) = end_width {
+                    width = end_width;
+                }
+            }
+
+            lines.push((line, width));
+        }
+
+        lines
+    }
+}
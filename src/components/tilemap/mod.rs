@@ -0,0 +1,392 @@
+pub mod tile;
+pub mod tile_animation;
+pub mod tile_layer;
+pub mod tile_layout;
+
+pub use tile::{Tile, TileFlags};
+pub use tile_animation::TileAnimation;
+pub use tile_layer::TileLayer;
+pub use tile_layout::{StaggerAxis, StaggerIndex, TileLayout};
+
+use crate::{
+    assets::{
+        shape::{Bounds, Vertex2},
+        Shape, TextureAtlas,
+    },
+    components::Sprite,
+    Context,
+};
+use nalgebra::{Rotation2, Vector2};
+use parking_lot::RwLock;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+use vulkano::pipeline::graphics::input_assembly::PrimitiveTopology;
+
+pub const CHUNK_SIZE: u32 = 16;
+
+type ChunkKey = (usize, Vector2<u32>);
+
+pub struct Tilemap {
+    size: Vector2<u32>,
+    tile_size: Vector2<f32>,
+    layout: TileLayout,
+    tileset: TextureAtlas,
+    chunk_size: u32,
+    layers: Vec<TileLayer>,
+    animations: HashMap<u32, TileAnimation>,
+    elapsed: Duration,
+    chunks: HashMap<ChunkKey, Arc<RwLock<Sprite>>>,
+    dirty: HashSet<ChunkKey>,
+    tile_chunks: HashMap<u32, HashMap<ChunkKey, usize>>,
+}
+
+impl Tilemap {
+    pub fn new(
+        tileset: TextureAtlas,
+        size: Vector2<u32>,
+        tile_size: Vector2<f32>,
+        layout: TileLayout,
+    ) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            size,
+            tile_size,
+            layout,
+            tileset,
+            chunk_size: CHUNK_SIZE,
+            layers: Vec::new(),
+            animations: HashMap::new(),
+            elapsed: Duration::ZERO,
+            chunks: HashMap::new(),
+            dirty: HashSet::new(),
+            tile_chunks: HashMap::new(),
+        }))
+    }
+
+    pub fn size(&self) -> Vector2<u32> {
+        self.size
+    }
+
+    pub fn tile_size(&self) -> Vector2<f32> {
+        self.tile_size
+    }
+
+    pub fn set_tile_size(&mut self, tile_size: Vector2<f32>) {
+        self.tile_size = tile_size;
+
+        self.rebuild();
+    }
+
+    pub fn layout(&self) -> TileLayout {
+        self.layout
+    }
+
+    pub fn set_layout(&mut self, layout: TileLayout) {
+        self.layout = layout;
+
+        self.rebuild();
+    }
+
+    pub fn tileset(&self) -> &TextureAtlas {
+        &self.tileset
+    }
+
+    pub fn set_tileset(&mut self, tileset: TextureAtlas) {
+        self.tileset = tileset;
+
+        self.rebuild();
+    }
+
+    pub fn chunk_size(&self) -> u32 {
+        self.chunk_size
+    }
+
+    pub fn set_chunk_size(&mut self, chunk_size: u32) {
+        self.chunk_size = chunk_size.max(1);
+
+        self.rebuild();
+    }
+
+    pub fn layers(&self) -> &[TileLayer] {
+        &self.layers
+    }
+
+    pub fn layer(&self, index: usize) -> Option<&TileLayer> {
+        self.layers.get(index)
+    }
+
+    pub fn layer_mut(&mut self, index: usize) -> Option<&mut TileLayer> {
+        self.layers.get_mut(index)
+    }
+
+    pub fn find_layer(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|l| l.name == name)
+    }
+
+    pub fn add_layer(&mut self, name: impl Into<String>, layer: u32) -> usize {
+        self.layers
+            .push(TileLayer::new(name.into(), layer, self.size));
+
+        self.layers.len() - 1
+    }
+
+    pub fn remove_layer(&mut self, index: usize) -> Option<TileLayer> {
+        (index < self.layers.len()).then(|| {
+            let layer = self.layers.remove(index);
+
+            self.rebuild();
+
+            layer
+        })
+    }
+
+    pub fn set_layer_offset(&mut self, index: usize, offset: Vector2<f32>) {
+        if let Some(layer) = self.layers.get_mut(index) {
+            layer.offset = offset;
+
+            self.dirty
+                .extend(self.chunks.keys().filter(|(i, _)| *i == index));
+        }
+    }
+
+    pub fn animation(&self, id: u32) -> Option<&TileAnimation> {
+        self.animations.get(&id)
+    }
+
+    pub fn set_animation(&mut self, id: u32, animation: Option<TileAnimation>) {
+        match animation {
+            Some(animation) => self.animations.insert(id, animation),
+            None => self.animations.remove(&id),
+        };
+
+        self.invalidate([id]);
+    }
+
+    pub fn animate(&mut self, delta: Duration) {
+        let previous = self.elapsed;
+
+        self.elapsed += delta;
+
+        let changed: Vec<_> = self
+            .animations
+            .iter()
+            .filter(|(_, a)| a.frame(previous) != a.frame(self.elapsed))
+            .map(|(id, _)| *id)
+            .collect();
+
+        self.invalidate(changed);
+    }
+
+    pub fn get(&self, layer: usize, cell: Vector2<u32>) -> Option<Tile> {
+        let index = self.index(cell)?;
+
+        self.layers.get(layer)?.tiles[index]
+    }
+
+    pub fn set(&mut self, layer: usize, cell: Vector2<u32>, tile: Option<Tile>) {
+        let Some(index) = self.index(cell) else {
+            return;
+        };
+        let chunk = cell / self.chunk_size;
+
+        if let Some(l) = self.layers.get_mut(layer) {
+            if l.tiles[index] != tile {
+                let key = (layer, chunk);
+
+                if let Some(old) = std::mem::replace(&mut l.tiles[index], tile) {
+                    if let Some(chunks) = self.tile_chunks.get_mut(&old.id) {
+                        if let Some(count) = chunks.get_mut(&key) {
+                            *count -= 1;
+
+                            if *count == 0 {
+                                chunks.remove(&key);
+                            }
+                        }
+
+                        if chunks.is_empty() {
+                            self.tile_chunks.remove(&old.id);
+                        }
+                    }
+                }
+
+                if let Some(new) = tile {
+                    *self
+                        .tile_chunks
+                        .entry(new.id)
+                        .or_default()
+                        .entry(key)
+                        .or_default() += 1;
+                }
+
+                self.dirty.insert(key);
+            }
+        }
+    }
+
+    pub fn cell(&self, cell: Vector2<u32>) -> Bounds {
+        self.layout.cell(cell, self.size, self.tile_size)
+    }
+
+    pub fn chunks(&self) -> impl Iterator<Item = (&TileLayer, &Arc<RwLock<Sprite>>)> {
+        self.chunks
+            .iter()
+            .filter_map(|((layer, _), sprite)| Some((self.layers.get(*layer)?, sprite)))
+            .filter(|(layer, _)| layer.visible)
+    }
+
+    pub fn update(&mut self, context: &Context) -> anyhow::Result<()> {
+        for key in std::mem::take(&mut self.dirty) {
+            let (vertices, indices) = self.quads(key);
+
+            if vertices.is_empty() {
+                self.chunks.remove(&key);
+
+                continue;
+            }
+
+            let shape = Shape::indexed(
+                context,
+                &vertices,
+                &indices,
+                PrimitiveTopology::TriangleList,
+            )?;
+
+            match self.chunks.get(&key) {
+                Some(sprite) => {
+                    let mut s = sprite.write();
+
+                    s.shape = shape;
+                    s.texture = self.tileset.texture.clone();
+                }
+                None => {
+                    let layer = &self.layers[key.0];
+                    let sprite = Sprite::new(
+                        context,
+                        shape,
+                        self.tileset.texture.clone(),
+                        layer.color,
+                        layer.layer,
+                    )?;
+
+                    self.chunks.insert(key, sprite);
+                }
+            }
+        }
+
+        for ((layer, _), sprite) in &self.chunks {
+            if let Some(layer) = self.layers.get(*layer) {
+                let mut s = sprite.write();
+
+                s.color = layer.color;
+                s.layer = layer.layer;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn invalidate<I: IntoIterator<Item = u32>>(&mut self, ids: I) {
+        for id in ids {
+            if let Some(chunks) = self.tile_chunks.get(&id) {
+                self.dirty.extend(chunks.keys());
+            }
+        }
+    }
+
+    fn index(&self, cell: Vector2<u32>) -> Option<usize> {
+        (cell.x < self.size.x && cell.y < self.size.y)
+            .then_some((cell.y * self.size.x + cell.x) as usize)
+    }
+
+    fn rebuild(&mut self) {
+        let chunks = self.size.map(|s| s.div_ceil(self.chunk_size));
+
+        self.chunks.clear();
+        self.tile_chunks.clear();
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            for (index, tile) in layer.tiles.iter().enumerate() {
+                if let Some(tile) = tile {
+                    let cell = Vector2::new(index as u32 % self.size.x, index as u32 / self.size.x);
+
+                    *self
+                        .tile_chunks
+                        .entry(tile.id)
+                        .or_default()
+                        .entry((i, cell / self.chunk_size))
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        self.dirty = (0..self.layers.len())
+            .flat_map(|layer| {
+                (0..chunks.y)
+                    .flat_map(move |y| (0..chunks.x).map(move |x| (layer, Vector2::new(x, y))))
+            })
+            .collect();
+    }
+
+    fn quads(&self, (layer, chunk): ChunkKey) -> (Vec<Vertex2>, Vec<u32>) {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let Some(l) = self.layers.get(layer) else {
+            return (vertices, indices);
+        };
+        let [width, height, _] = self.tileset.texture.image.image().extent();
+        let dimensions = Vector2::new(width as f32, height as f32);
+        let min = chunk * self.chunk_size;
+        let max = (min + Vector2::repeat(self.chunk_size)).inf(&self.size);
+
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let cell = Vector2::new(x, y);
+                let Some(tile) = self.index(cell).and_then(|i| l.tiles[i]) else {
+                    continue;
+                };
+                let id = self
+                    .animations
+                    .get(&tile.id)
+                    .and_then(|a| a.frame(self.elapsed))
+                    .unwrap_or(tile.id);
+                let Some(region) = self.tileset.frame(id as usize) else {
+                    continue;
+                };
+                let bounds = self.cell(cell);
+                let size = region.frame_size().component_mul(&dimensions);
+                let origin = Vector2::new(
+                    (bounds.min.x + bounds.max.x - size.x) / 2.0,
+                    bounds.max.y - size.y,
+                ) + l.offset;
+                let (flags, rotation) = if self.layout.is_hexagonal() {
+                    tile.flags.hexagonal()
+                } else {
+                    (tile.flags, 0.0)
+                };
+                let rotation = Rotation2::new(rotation);
+                let center = origin + size / 2.0;
+                let start = vertices.len() as u32;
+
+                vertices.extend(
+                    [
+                        Vector2::new(0.0, 0.0),
+                        Vector2::new(1.0, 0.0),
+                        Vector2::new(1.0, 1.0),
+                        Vector2::new(0.0, 1.0),
+                    ]
+                    .map(|c| {
+                        Vertex2::new(
+                            center + rotation * (size.component_mul(&c) - size / 2.0),
+                            region.uv(flags.apply(c)),
+                        )
+                    }),
+                );
+                indices.extend([0, 1, 2, 0, 2, 3].map(|i| start + i));
+            }
+        }
+
+        (vertices, indices)
+    }
+}
@@ -0,0 +1,78 @@
+use nalgebra::Vector2;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TileFlags {
+    pub flip_x: bool,
+    pub flip_y: bool,
+    pub diagonal: bool,
+    pub rotated_hexagonal: bool,
+}
+
+impl TileFlags {
+    pub fn rotation(quarter_turns: u32) -> Self {
+        let (flip_x, flip_y, diagonal) = match quarter_turns % 4 {
+            0 => (false, false, false),
+            1 => (true, false, true),
+            2 => (true, true, false),
+            _ => (false, true, true),
+        };
+
+        Self {
+            flip_x,
+            flip_y,
+            diagonal,
+            rotated_hexagonal: false,
+        }
+    }
+
+    pub fn hexagonal(&self) -> (Self, f32) {
+        let degrees = if self.diagonal { 60.0 } else { 0.0 }
+            + if self.rotated_hexagonal { 120.0 } else { 0.0 };
+
+        (
+            Self {
+                diagonal: false,
+                rotated_hexagonal: false,
+                ..*self
+            },
+            f32::to_radians(degrees),
+        )
+    }
+
+    pub fn apply(&self, uv: Vector2<f32>) -> Vector2<f32> {
+        let mut uv = uv;
+
+        if self.flip_x {
+            uv.x = 1.0 - uv.x;
+        }
+
+        if self.flip_y {
+            uv.y = 1.0 - uv.y;
+        }
+
+        if self.diagonal {
+            uv = uv.yx();
+        }
+
+        uv
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub id: u32,
+    pub flags: TileFlags,
+}
+
+impl Tile {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            flags: TileFlags::default(),
+        }
+    }
+
+    pub fn with_flags(id: u32, flags: TileFlags) -> Self {
+        Self { id, flags }
+    }
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TileAnimation {
+    pub frames: Vec<(u32, Duration)>,
+}
+
+impl TileAnimation {
+    pub fn new(frames: Vec<(u32, Duration)>) -> Self {
+        Self { frames }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|(_, d)| *d).sum()
+    }
+
+    pub fn frame(&self, elapsed: Duration) -> Option<u32> {
+        let duration = self.duration();
+
+        if duration.is_zero() {
+            return self.frames.first().map(|(id, _)| *id);
+        }
+
+        let mut time = Duration::from_nanos((elapsed.as_nanos() % duration.as_nanos()) as u64);
+
+        self.frames.iter().find_map(|(id, d)| {
+            if time < *d {
+                Some(*id)
+            } else {
+                time -= *d;
+
+                None
+            }
+        })
+    }
+}
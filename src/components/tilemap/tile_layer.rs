@@ -0,0 +1,33 @@
+use super::Tile;
+use nalgebra::{Vector2, Vector4};
+
+#[derive(Clone, Debug)]
+pub struct TileLayer {
+    pub name: String,
+    pub visible: bool,
+    pub color: Vector4<f32>,
+    pub layer: u32,
+    pub(crate) offset: Vector2<f32>,
+    pub(crate) tiles: Vec<Option<Tile>>,
+}
+
+impl TileLayer {
+    pub(crate) fn new(name: String, layer: u32, size: Vector2<u32>) -> Self {
+        Self {
+            name,
+            visible: true,
+            color: Vector4::repeat(1.0),
+            layer,
+            offset: Vector2::zeros(),
+            tiles: vec![None; (size.x * size.y) as usize],
+        }
+    }
+
+    pub fn offset(&self) -> Vector2<f32> {
+        self.offset
+    }
+
+    pub fn tiles(&self) -> &[Option<Tile>] {
+        &self.tiles
+    }
+}
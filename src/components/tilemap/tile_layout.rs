@@ -0,0 +1,65 @@
+use crate::assets::shape::Bounds;
+use nalgebra::Vector2;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StaggerAxis {
+    X,
+    #[default]
+    Y,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StaggerIndex {
+    #[default]
+    Odd,
+    Even,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TileLayout {
+    #[default]
+    Orthogonal,
+    Isometric,
+    Hexagonal {
+        side_length: f32,
+        axis: StaggerAxis,
+        index: StaggerIndex,
+    },
+}
+
+impl TileLayout {
+    pub fn is_hexagonal(&self) -> bool {
+        matches!(*self, Self::Hexagonal { side_length, .. } if side_length > 0.0)
+    }
+
+    pub fn cell(&self, cell: Vector2<u32>, size: Vector2<u32>, tile: Vector2<f32>) -> Bounds {
+        let c = cell.cast::<f32>();
+        let min = match *self {
+            Self::Orthogonal => c.component_mul(&tile),
+            Self::Isometric => Vector2::new(
+                (c.x - c.y + size.y.saturating_sub(1) as f32) * tile.x / 2.0,
+                (c.x + c.y) * tile.y / 2.0,
+            ),
+            Self::Hexagonal {
+                side_length,
+                axis,
+                index,
+            } => {
+                let (main, cross) = match axis {
+                    StaggerAxis::X => (0, 1),
+                    StaggerAxis::Y => (1, 0),
+                };
+                let staggered = (cell[main] % 2 == 1) == (index == StaggerIndex::Odd);
+                let mut min = Vector2::zeros();
+
+                min[main] = c[main] * (tile[main] + side_length) / 2.0;
+                min[cross] =
+                    c[cross] * tile[cross] + if staggered { tile[cross] / 2.0 } else { 0.0 };
+
+                min
+            }
+        };
+
+        Bounds::new(min, min + tile)
+    }
+}
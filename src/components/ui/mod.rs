@@ -0,0 +1,54 @@
+pub mod style;
+pub mod widget;
+
+pub use style::{Align, Direction, Edges, Justify, Size, UiStyle};
+pub use widget::{UiState, Widget};
+
+use crate::{assets::shape::Bounds, Id};
+use nalgebra::{Vector2, Vector4};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct UiNode {
+    pub parent: Option<Id>,
+    pub order: i32,
+    pub style: UiStyle,
+    pub background: Option<Vector4<f32>>,
+    pub foreground: Vector4<f32>,
+    pub widget: Option<Widget>,
+    pub visible: bool,
+    pub(crate) rect: Bounds,
+    pub(crate) state: UiState,
+}
+
+impl UiNode {
+    pub fn new(parent: Option<Id>, style: UiStyle) -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Self {
+            parent,
+            order: 0,
+            style,
+            background: None,
+            foreground: Vector4::repeat(1.0),
+            widget: None,
+            visible: true,
+            rect: Bounds::new(Vector2::zeros(), Vector2::zeros()),
+            state: UiState::default(),
+        }))
+    }
+
+    pub fn rect(&self) -> Bounds {
+        self.rect
+    }
+
+    pub fn state(&self) -> UiState {
+        self.state
+    }
+
+    pub fn content(&self) -> Bounds {
+        Bounds::new(
+            self.rect.min + self.style.padding.start(),
+            self.rect.max - self.style.padding.end(),
+        )
+    }
+}
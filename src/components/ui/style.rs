@@ -0,0 +1,108 @@
+use nalgebra::Vector2;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Size {
+    #[default]
+    Auto,
+    Px(f32),
+    Percent(f32),
+    Fill(f32),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Row,
+    #[default]
+    Column,
+    Overlay,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+    Stretch,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Justify {
+    #[default]
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Edges {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Edges {
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    pub fn all(value: f32) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    pub fn start(&self) -> Vector2<f32> {
+        Vector2::new(self.left, self.top)
+    }
+
+    pub fn end(&self) -> Vector2<f32> {
+        Vector2::new(self.right, self.bottom)
+    }
+
+    pub fn sum(&self) -> Vector2<f32> {
+        self.start() + self.end()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UiStyle {
+    pub width: Size,
+    pub height: Size,
+    pub margin: Edges,
+    pub padding: Edges,
+    pub direction: Direction,
+    pub gap: f32,
+    pub align: Align,
+    pub justify: Justify,
+    pub anchor: Vector2<f32>,
+    pub offset: Vector2<f32>,
+}
+
+impl Default for UiStyle {
+    fn default() -> Self {
+        Self {
+            width: Size::default(),
+            height: Size::default(),
+            margin: Edges::default(),
+            padding: Edges::default(),
+            direction: Direction::default(),
+            gap: 0.0,
+            align: Align::default(),
+            justify: Justify::default(),
+            anchor: Vector2::zeros(),
+            offset: Vector2::zeros(),
+        }
+    }
+}
+
+impl UiStyle {
+    pub fn size(&self) -> [Size; 2] {
+        [self.width, self.height]
+    }
+}
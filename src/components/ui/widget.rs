@@ -0,0 +1,20 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum Widget {
+    Button,
+    Slider {
+        value: f32,
+        min: f32,
+        max: f32,
+    },
+    TextInput {
+        text: String,
+        max_len: Option<usize>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UiState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+}
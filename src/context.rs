@@ -1,12 +1,22 @@
-use crate::{Control, World};
+use crate::{
+    assets::{upload_queue::UploadBuilder, RenderTarget, SamplerPreset, UploadQueue},
+    components::Camera,
+    resources::{DebugDraw, PostProcess, Time},
+    world::EntityManager,
+    Control, Id, World,
+};
 use nalgebra::Vector4;
-use parking_lot::RwLock;
-use std::sync::Arc;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 use threadpool::ThreadPool;
 use vulkano::{
     command_buffer::{
         allocator::StandardCommandBufferAllocator, AutoCommandBufferBuilder, CommandBufferUsage,
-        RenderPassBeginInfo,
+        PrimaryAutoCommandBuffer, RenderPassBeginInfo,
     },
     descriptor_set::allocator::StandardDescriptorSetAllocator,
     device::{
@@ -14,17 +24,18 @@ use vulkano::{
         QueueCreateInfo, QueueFlags,
     },
     format::Format,
-    image::{view::ImageView, Image, ImageCreateInfo, ImageType, ImageUsage},
+    image::{sampler::Sampler, view::ImageView, Image, ImageUsage},
     instance::{Instance, InstanceCreateFlags, InstanceCreateInfo},
-    memory::allocator::{AllocationCreateInfo, StandardMemoryAllocator},
-    pipeline::graphics::viewport::Viewport,
+    memory::allocator::StandardMemoryAllocator,
+    pipeline::{graphics::viewport::Viewport, GraphicsPipeline},
     render_pass::{Framebuffer, FramebufferCreateInfo, RenderPass},
+    shader::{EntryPoint, ShaderModule},
     swapchain::{
         acquire_next_image, PresentMode, Surface, Swapchain, SwapchainCreateInfo,
         SwapchainPresentInfo,
     },
     sync::{self, GpuFuture},
-    Validated, VulkanError, VulkanLibrary,
+    Handle, Validated, VulkanError, VulkanLibrary, VulkanObject,
 };
 use winit::{
     event::{Event, WindowEvent},
@@ -32,6 +43,10 @@ use winit::{
     window::Window,
 };
 
+pub type ShaderLoader = fn(Arc<Device>) -> Result<Arc<ShaderModule>, Validated<VulkanError>>;
+pub type CachedPipeline = (Vec<u64>, Arc<GraphicsPipeline>);
+pub type View = (Option<Id>, Arc<Framebuffer>, Viewport, Vector4<f32>);
+
 pub struct Context {
     pub device: Arc<Device>,
     pub queue: Arc<Queue>,
@@ -39,9 +54,12 @@ pub struct Context {
     pub command_buffer_allocator: Arc<StandardCommandBufferAllocator>,
     pub descriptor_set_allocator: Arc<StandardDescriptorSetAllocator>,
     pub render_pass: Arc<RenderPass>,
+    pub present_pass: Arc<RenderPass>,
     pub surface: Arc<Surface>,
     pub images: Vec<Arc<Image>>,
     pub framebuffers: Vec<Arc<Framebuffer>>,
+    pub scene: Vec<RenderTarget>,
+    pub overlay: Option<RenderTarget>,
     pub swapchain: Arc<Swapchain>,
     pub window: Arc<Window>,
     pub viewport: Viewport,
@@ -49,9 +67,15 @@ pub struct Context {
     pub present_mode: PresentMode,
     pub pool: ThreadPool,
     pub bg: Vector4<f32>,
+    pub shaders: Mutex<HashMap<&'static str, EntryPoint>>,
+    pub pipelines: Mutex<HashMap<u64, CachedPipeline>>,
+    pub samplers: Mutex<HashMap<SamplerPreset, Arc<Sampler>>>,
+    pub uploads: Mutex<UploadQueue>,
 }
 
 impl Context {
+    pub const SCENE_FORMAT: Format = Format::R16G16B16A16_SFLOAT;
+
     pub fn new(
         event_loop: &EventLoop<()>,
         window: Arc<Window>,
@@ -139,7 +163,7 @@ impl Context {
             device.clone(),
             attachments: {
                 color: {
-                    format: swapchain.image_format(),
+                    format: Self::SCENE_FORMAT,
                     samples: 1,
                     load_op: Clear,
                     store_op: Store,
@@ -156,6 +180,21 @@ impl Context {
                 depth_stencil: {depth_stencil},
             },
         )?;
+        let present_pass = vulkano::single_pass_renderpass!(
+            device.clone(),
+            attachments: {
+                color: {
+                    format: swapchain.image_format(),
+                    samples: 1,
+                    load_op: DontCare,
+                    store_op: Store,
+                },
+            },
+            pass: {
+                color: [color],
+                depth_stencil: {},
+            },
+        )?;
         let descriptor_set_allocator = Arc::new(StandardDescriptorSetAllocator::new(
             device.clone(),
             Default::default(),
@@ -164,19 +203,18 @@ impl Context {
             device.clone(),
             Default::default(),
         ));
-        let (framebuffers, viewport) = Self::window_size_dependent_setup(
-            memory_allocator.clone(),
-            &images,
-            render_pass.clone(),
-        )?;
-
-        Ok(Arc::new(RwLock::new(Self {
+        let (framebuffers, viewport) =
+            Self::window_size_dependent_setup(&images, present_pass.clone())?;
+        let mut context = Self {
             present_mode,
             framebuffers,
+            scene: Vec::new(),
+            overlay: None,
             images,
             surface,
             previous_frame_end: Some(sync::now(device.clone()).boxed_send_sync()),
             render_pass,
+            present_pass,
             viewport,
             command_buffer_allocator,
             descriptor_set_allocator,
@@ -187,7 +225,94 @@ impl Context {
             swapchain,
             pool,
             bg,
-        })))
+            shaders: Default::default(),
+            pipelines: Default::default(),
+            samplers: Default::default(),
+            uploads: Default::default(),
+        };
+
+        context.scene = context.scene_targets()?;
+        context.overlay = Some(context.overlay_target()?);
+
+        Ok(Arc::new(RwLock::new(context)))
+    }
+
+    pub fn shader(&self, name: &'static str, load: ShaderLoader) -> anyhow::Result<EntryPoint> {
+        if let Some(entry_point) = self.shaders.lock().get(name) {
+            return Ok(entry_point.clone());
+        }
+
+        let entry_point = load(self.device.clone())?
+            .entry_point("main")
+            .ok_or_else(|| anyhow::anyhow!("Shader {name} has no main entry point"))?;
+
+        self.shaders.lock().insert(name, entry_point.clone());
+
+        Ok(entry_point)
+    }
+
+    pub fn pipeline<K, F>(&self, key: K, create: F) -> anyhow::Result<Arc<GraphicsPipeline>>
+    where
+        K: Hash,
+        F: FnOnce() -> anyhow::Result<Arc<GraphicsPipeline>>,
+    {
+        self.shader_pipeline(key, &[], create)
+    }
+
+    pub fn shader_pipeline<K, F>(
+        &self,
+        key: K,
+        shaders: &[&EntryPoint],
+        create: F,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>>
+    where
+        K: Hash,
+        F: FnOnce() -> anyhow::Result<Arc<GraphicsPipeline>>,
+    {
+        let modules: Vec<_> = shaders
+            .iter()
+            .map(|s| s.module().handle().as_raw())
+            .collect();
+        let key = {
+            let mut hasher = DefaultHasher::new();
+
+            key.hash(&mut hasher);
+            modules.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        if let Some((_, pipeline)) = self.pipelines.lock().get(&key) {
+            return Ok(pipeline.clone());
+        }
+
+        let pipeline = create()?;
+
+        self.pipelines
+            .lock()
+            .insert(key, (modules, pipeline.clone()));
+
+        Ok(pipeline)
+    }
+
+    pub fn evict_pipelines(&self, shader: &EntryPoint) {
+        let module = shader.module().handle().as_raw();
+
+        self.pipelines
+            .lock()
+            .retain(|_, (modules, _)| !modules.contains(&module));
+    }
+
+    pub fn sampler(&self, preset: SamplerPreset) -> anyhow::Result<Arc<Sampler>> {
+        if let Some(sampler) = self.samplers.lock().get(&preset) {
+            return Ok(sampler.clone());
+        }
+
+        let sampler = preset.create(self.device.clone())?;
+
+        self.samplers.lock().insert(preset, sampler.clone());
+
+        Ok(sampler)
     }
 
     pub fn init(
@@ -222,6 +347,23 @@ impl Context {
         control: Arc<RwLock<Control>>,
         (elwt, recreate_swapchain): (&EventLoopWindowTarget<()>, &mut bool),
     ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let res = world.read().res.clone();
+            let res = res.read();
+
+            if let Some(time) = res.get::<Time>() {
+                time.write().tick();
+            }
+
+            if let Some(debug) = res.get::<DebugDraw>() {
+                debug.write().clear();
+            }
+        }
+
         let sm = world.read().sm.clone();
 
         sm.write()
@@ -234,7 +376,7 @@ impl Context {
                 event: WindowEvent::RedrawRequested,
                 window_id,
             } if window_id == { context.read().window.id() } => {
-                let (mut builder, rs, suboptimal, acquire_future, image_index) = {
+                let (builder, rs, suboptimal, acquire_future, image_index) = {
                     let mut context = context.write();
                     let image_extent: [u32; 2] = context.window.inner_size().into();
 
@@ -248,7 +390,7 @@ impl Context {
                         .unwrap()
                         .cleanup_finished();
 
-                    let mut builder = AutoCommandBufferBuilder::primary(
+                    let builder = AutoCommandBufferBuilder::primary(
                         &context.command_buffer_allocator,
                         context.queue.queue_family_index(),
                         CommandBufferUsage::OneTimeSubmit,
@@ -268,13 +410,14 @@ impl Context {
                         context.images = new_images;
 
                         let (framebuffers, viewport) = Self::window_size_dependent_setup(
-                            context.memory_allocator.clone(),
                             &context.images,
-                            context.render_pass.clone(),
+                            context.present_pass.clone(),
                         )?;
 
                         context.framebuffers = framebuffers;
                         context.viewport = viewport;
+                        context.scene = context.scene_targets()?;
+                        context.overlay = Some(context.overlay_target()?);
 
                         *recreate_swapchain = false;
                     }
@@ -292,35 +435,26 @@ impl Context {
                             Err(e) => return Err(e.into()),
                         };
 
-                    builder
-                        .begin_render_pass(
-                            RenderPassBeginInfo {
-                                clear_values: vec![
-                                    Some(<[f32; 4]>::from(context.bg).into()),
-                                    Some(1f32.into()),
-                                ],
-                                ..RenderPassBeginInfo::framebuffer(
-                                    context.framebuffers[image_index as usize].clone(),
-                                )
-                            },
-                            Default::default(),
-                        )?
-                        .set_viewport(0, [context.viewport.clone()].into_iter().collect())?;
-
                     (builder, rs, suboptimal, acquire_future, image_index)
                 };
 
-                let rm = world.read().rm.clone();
-
-                rm.write().draw(
-                    &mut (control.clone(), &mut builder, rs),
+                let uploads = context.read().uploads.lock().take();
+                let command_buffer = match Self::draw_frame(
                     context.clone(),
                     world.clone(),
-                )?;
-
-                builder.end_render_pass(Default::default())?;
-
-                let command_buffer = builder.build()?;
+                    control.clone(),
+                    builder,
+                    &uploads,
+                    rs,
+                    image_index,
+                ) {
+                    Ok(command_buffer) => command_buffer,
+                    Err(e) => {
+                        context.read().uploads.lock().requeue(uploads);
+
+                        return Err(e);
+                    }
+                };
                 let mut context = context.write();
 
                 if suboptimal {
@@ -328,12 +462,23 @@ impl Context {
                 }
 
                 {
-                    let future = context
+                    let future = match context
                         .previous_frame_end
                         .take()
                         .unwrap()
                         .join(acquire_future)
-                        .then_execute(context.queue.clone(), command_buffer)?
+                        .then_execute(context.queue.clone(), command_buffer)
+                    {
+                        Ok(future) => future,
+                        Err(e) => {
+                            context.uploads.lock().requeue(uploads);
+                            context.previous_frame_end =
+                                Some(sync::now(context.device.clone()).boxed_send_sync());
+
+                            return Err(e.into());
+                        }
+                    };
+                    let future = future
                         .then_swapchain_present(
                             context.queue.clone(),
                             SwapchainPresentInfo::swapchain_image_index(
@@ -346,14 +491,20 @@ impl Context {
                     match future.map_err(Validated::unwrap) {
                         Ok(future) => {
                             context.previous_frame_end = Some(future.boxed_send_sync());
+
+                            uploads.complete();
                         }
                         Err(VulkanError::OutOfDate) => {
                             *recreate_swapchain = true;
 
+                            context.uploads.lock().requeue(uploads);
+
                             context.previous_frame_end =
                                 Some(sync::now(context.device.clone()).boxed_send_sync());
                         }
                         Err(_) => {
+                            context.uploads.lock().requeue(uploads);
+
                             context.previous_frame_end =
                                 Some(sync::now(context.device.clone()).boxed_send_sync());
                         }
@@ -384,23 +535,145 @@ impl Context {
         Ok(())
     }
 
+    fn draw_frame(
+        context: Arc<RwLock<Self>>,
+        world: Arc<RwLock<World>>,
+        control: Arc<RwLock<Control>>,
+        mut builder: UploadBuilder,
+        uploads: &UploadQueue,
+        rs: bool,
+        image_index: u32,
+    ) -> anyhow::Result<Arc<PrimaryAutoCommandBuffer<Arc<StandardCommandBufferAllocator>>>> {
+        uploads.record(&mut builder)?;
+
+        let views = {
+            let em = world.read().em.clone();
+            let em = em.read();
+
+            context.read().views(&em)
+        };
+        let rm = world.read().rm.clone();
+        let main = views.last().and_then(|(camera, ..)| *camera);
+
+        for (camera, framebuffer, viewport, bg) in views {
+            builder
+                .begin_render_pass(
+                    RenderPassBeginInfo {
+                        clear_values: vec![Some(<[f32; 4]>::from(bg).into()), Some(1f32.into())],
+                        ..RenderPassBeginInfo::framebuffer(framebuffer)
+                    },
+                    Default::default(),
+                )?
+                .set_viewport(0, [viewport].into_iter().collect())?;
+
+            rm.write().draw(
+                &mut (control.clone(), &mut builder, rs, camera),
+                context.clone(),
+                world.clone(),
+            )?;
+
+            builder.end_render_pass(Default::default())?;
+        }
+
+        let (overlay, viewport) = {
+            let context = context.read();
+
+            (context.overlay.clone(), context.viewport.clone())
+        };
+
+        if let Some(overlay) = overlay {
+            builder
+                .begin_render_pass(
+                    RenderPassBeginInfo {
+                        clear_values: vec![Some([0.0f32; 4].into()), Some(1f32.into())],
+                        ..RenderPassBeginInfo::framebuffer(overlay.framebuffer)
+                    },
+                    Default::default(),
+                )?
+                .set_viewport(0, [viewport].into_iter().collect())?;
+
+            rm.write().overlay(
+                &mut (control.clone(), &mut builder, rs, main),
+                context.clone(),
+                world.clone(),
+            )?;
+
+            builder.end_render_pass(Default::default())?;
+        }
+
+        let (post, time) = {
+            let res = world.read().res.clone();
+            let res = res.read();
+
+            (
+                res.get::<PostProcess>()
+                    .map(|p| p.read().clone())
+                    .unwrap_or_default(),
+                res.get::<Time>()
+                    .map(|t| t.read().elapsed().as_secs_f32())
+                    .unwrap_or_default(),
+            )
+        };
+
+        post.apply(&context.read(), &mut builder, image_index, time)?;
+
+        Ok(builder.build()?)
+    }
+
+    fn views(&self, em: &EntityManager) -> Vec<View> {
+        let mut views: Vec<_> = em
+            .entities()
+            .filter_map(|e| {
+                let c = em.get_component::<Camera>(e)?;
+                let mut c = c.write();
+                let target = c.target()?.clone();
+                let viewport = target.viewport();
+
+                c.set_viewport(viewport.extent.into());
+
+                Some((Some(e), target.framebuffer, viewport, target.bg))
+            })
+            .collect();
+        let camera = em
+            .entities()
+            .filter(|e| {
+                em.get_component::<Camera>(*e)
+                    .is_some_and(|c| c.read().target().is_none())
+            })
+            .min();
+
+        if let Some(c) = camera.and_then(|e| em.get_component::<Camera>(e)) {
+            c.write().set_viewport(self.viewport.extent.into());
+        }
+
+        views.push((
+            camera,
+            self.scene[0].framebuffer.clone(),
+            self.viewport.clone(),
+            self.bg,
+        ));
+
+        views
+    }
+
+    fn scene_targets(&self) -> anyhow::Result<Vec<RenderTarget>> {
+        let [width, height, _] = self.images[0].extent();
+
+        (0..3)
+            .map(|_| RenderTarget::new(self, width, height, self.bg))
+            .collect()
+    }
+
+    fn overlay_target(&self) -> anyhow::Result<RenderTarget> {
+        let [width, height, _] = self.images[0].extent();
+
+        RenderTarget::new(self, width, height, Vector4::zeros())
+    }
+
     fn window_size_dependent_setup(
-        memory_allocator: Arc<StandardMemoryAllocator>,
         images: &[Arc<Image>],
         render_pass: Arc<RenderPass>,
     ) -> anyhow::Result<(Vec<Arc<Framebuffer>>, Viewport)> {
-        let depth_buffer = ImageView::new_default(Image::new(
-            memory_allocator,
-            ImageCreateInfo {
-                image_type: ImageType::Dim2d,
-                format: Format::D16_UNORM,
-                extent: images[0].extent(),
-                usage: ImageUsage::DEPTH_STENCIL_ATTACHMENT | ImageUsage::TRANSIENT_ATTACHMENT,
-                ..Default::default()
-            },
-            AllocationCreateInfo::default(),
-        )?)?;
-
         let images: anyhow::Result<Vec<Arc<Framebuffer>>> = images
             .iter()
             .map(|image| {
@@ -409,7 +682,7 @@ impl Context {
                 Ok(Framebuffer::new(
                     render_pass.clone(),
                     FramebufferCreateInfo {
-                        attachments: vec![view, depth_buffer.clone()],
+                        attachments: vec![view],
                         ..Default::default()
                     },
                 )?)
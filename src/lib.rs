@@ -5,6 +5,8 @@ pub mod control;
 pub mod drawable;
 pub mod id;
 pub mod renderers;
+pub mod resources;
+pub mod systems;
 pub mod world;
 
 pub use anyhow;
@@ -0,0 +1,58 @@
+use super::layering;
+use crate::{
+    components::{BitmapText, Trans, UiNode},
+    world::renderer_manager::{Draw, DrawItem, Renderer},
+    Context, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+pub struct BitmapTextRenderer;
+
+impl Renderer for BitmapTextRenderer {
+    fn items(
+        &mut self,
+        draw: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<Vec<DrawItem>> {
+        let em = world.read().em.clone();
+        let em = em.read();
+        let Some(camera) = layering::camera(&em, draw.3) else {
+            return Ok(Vec::new());
+        };
+        let bounds = camera.1.read().bounds(&camera.2.read());
+
+        Ok(em
+            .entities()
+            .filter(|e| em.get_component::<UiNode>(*e).is_none())
+            .filter_map(|e| {
+                let b = em.get_component::<BitmapText>(e)?;
+                let t = em.get_component::<Trans>(e)?;
+                let (layer, key, d) = {
+                    let b = b.read();
+                    let t = t.read();
+
+                    if !b.bounds().transform(&t.matrix()).intersects(&bounds) {
+                        return None;
+                    }
+
+                    (
+                        b.layer,
+                        b.sort_key.unwrap_or(t.position().y),
+                        b.drawable.clone(),
+                    )
+                };
+
+                Some(layering::item(
+                    false,
+                    layer,
+                    key,
+                    d,
+                    (e, b, t),
+                    camera.clone(),
+                ))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    assets::{mesh::ColorVertex2, upload_queue::UploadBuilder},
+    components::mesh2d::{fragment, vertex},
+    Context,
+};
+use nalgebra::{Matrix3, Matrix4, Vector4};
+use std::sync::Arc;
+use vulkano::{
+    buffer::{
+        allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo},
+        BufferUsage,
+    },
+    descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet},
+    memory::allocator::MemoryTypeFilter,
+    padded::Padded,
+    pipeline::{
+        graphics::{
+            color_blend::{
+                AttachmentBlend, BlendFactor, ColorBlendAttachmentState, ColorBlendState,
+            },
+            depth_stencil::DepthStencilState,
+            input_assembly::{InputAssemblyState, PrimitiveTopology},
+            multisample::MultisampleState,
+            rasterization::RasterizationState,
+            vertex_input::{Vertex, VertexDefinition},
+            viewport::ViewportState,
+            GraphicsPipelineCreateInfo,
+        },
+        layout::PipelineDescriptorSetLayoutCreateInfo,
+        DynamicState, GraphicsPipeline, Pipeline, PipelineBindPoint, PipelineLayout,
+        PipelineShaderStageCreateInfo,
+    },
+    render_pass::Subpass,
+    DeviceSize,
+};
+
+pub struct ColorBatch;
+
+impl ColorBatch {
+    pub fn pipeline(
+        context: &Context,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        context.pipeline(("color_batch", topology), || {
+            Self::create_pipeline(context, topology)
+        })
+    }
+
+    pub fn draw(
+        context: &Context,
+        builder: &mut UploadBuilder,
+        (camera_transform, camera_proj): (Matrix3<f32>, Matrix4<f32>),
+        topology: PrimitiveTopology,
+        vertices: &[ColorVertex2],
+    ) -> anyhow::Result<()> {
+        if vertices.is_empty() {
+            return Ok(());
+        }
+
+        let allocator = |buffer_usage| {
+            SubbufferAllocator::new(
+                context.memory_allocator.clone(),
+                SubbufferAllocatorCreateInfo {
+                    buffer_usage,
+                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                        | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                    ..Default::default()
+                },
+            )
+        };
+        let uniform_allocator = allocator(BufferUsage::UNIFORM_BUFFER);
+        let pipeline = Self::pipeline(context, topology)?;
+        let layouts = pipeline.layout().set_layouts();
+        let view = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = vertex::View {
+                z: Padded(0.0),
+                transform: <[[f32; 3]; 3]>::from(Matrix3::<f32>::identity()).map(Padded),
+                camera_transform: <[[f32; 3]; 3]>::from(camera_transform).map(Padded),
+                camera_proj: camera_proj.into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[0].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+        let color = {
+            let subbuffer = uniform_allocator.allocate_sized()?;
+
+            *subbuffer.write()? = fragment::Color {
+                color: Vector4::repeat(1.0).into(),
+            };
+
+            PersistentDescriptorSet::new(
+                &context.descriptor_set_allocator,
+                layouts[1].clone(),
+                [WriteDescriptorSet::buffer(0, subbuffer)],
+                [],
+            )?
+        };
+        let buffer = allocator(BufferUsage::VERTEX_BUFFER)
+            .allocate_slice::<ColorVertex2>(vertices.len() as DeviceSize)?;
+
+        buffer.write()?.copy_from_slice(vertices);
+
+        builder
+            .bind_pipeline_graphics(pipeline.clone())?
+            .bind_descriptor_sets(
+                PipelineBindPoint::Graphics,
+                pipeline.layout().clone(),
+                0,
+                vec![view, color],
+            )?
+            .bind_vertex_buffers(0, buffer)?
+            .draw(vertices.len() as u32, 1, 0, 0)?;
+
+        Ok(())
+    }
+
+    fn create_pipeline(
+        context: &Context,
+        topology: PrimitiveTopology,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        let vertex = context.shader("mesh2d::vertex", vertex::load)?;
+        let fragment = context.shader("mesh2d::fragment", fragment::load)?;
+        let vertex_input_state =
+            ColorVertex2::per_vertex().definition(&vertex.info().input_interface)?;
+        let stages = [
+            PipelineShaderStageCreateInfo::new(vertex),
+            PipelineShaderStageCreateInfo::new(fragment),
+        ];
+        let layout = PipelineLayout::new(
+            context.device.clone(),
+            PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+                .into_pipeline_layout_create_info(context.device.clone())?,
+        )?;
+        let subpass = Subpass::from(context.render_pass.clone(), 0).unwrap();
+
+        Ok(GraphicsPipeline::new(
+            context.device.clone(),
+            None,
+            GraphicsPipelineCreateInfo {
+                stages: stages.into_iter().collect(),
+                vertex_input_state: Some(vertex_input_state),
+                input_assembly_state: Some(InputAssemblyState {
+                    topology,
+                    ..Default::default()
+                }),
+                viewport_state: Some(ViewportState::default()),
+                rasterization_state: Some(RasterizationState::default()),
+                depth_stencil_state: Some(DepthStencilState::default()),
+                multisample_state: Some(MultisampleState::default()),
+                color_blend_state: Some(ColorBlendState::with_attachment_states(
+                    subpass.num_color_attachments(),
+                    ColorBlendAttachmentState {
+                        blend: Some(AttachmentBlend {
+                            src_alpha_blend_factor: BlendFactor::One,
+                            ..AttachmentBlend::alpha()
+                        }),
+                        ..Default::default()
+                    },
+                )),
+                dynamic_state: [DynamicState::Viewport].into_iter().collect(),
+                subpass: Some(subpass.into()),
+                ..GraphicsPipelineCreateInfo::layout(layout)
+            },
+        )?)
+    }
+}
@@ -0,0 +1,71 @@
+use super::{layering, ColorBatch};
+use crate::{
+    resources::{DebugDraw, DebugSpace},
+    world::renderer_manager::{Draw, Renderer},
+    Context, World,
+};
+use nalgebra::{Matrix3, Orthographic3};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::pipeline::graphics::input_assembly::PrimitiveTopology;
+
+pub struct DebugRenderer;
+
+impl Renderer for DebugRenderer {
+    fn overlay(
+        &mut self,
+        draw: &mut Draw,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        let Some(debug) = world.read().res.read().get::<DebugDraw>() else {
+            return Ok(());
+        };
+        let Some((_, c, ct)) = ({
+            let em = world.read().em.clone();
+            let em = em.read();
+
+            layering::camera(&em, draw.3)
+        }) else {
+            return Ok(());
+        };
+        let batches = {
+            let c = c.read();
+            let viewport = c.viewport();
+            let debug = debug.read();
+
+            [
+                (
+                    ct.read().matrix(),
+                    c.proj(),
+                    debug.batch(DebugSpace::World).clone(),
+                ),
+                (
+                    Matrix3::identity(),
+                    Orthographic3::new(0.0, viewport.x, 0.0, viewport.y, -1.0, 1.0)
+                        .to_homogeneous(),
+                    debug.batch(DebugSpace::Screen).clone(),
+                ),
+            ]
+        };
+        let context = context.read();
+        let (_, builder, _, _) = draw;
+
+        for (camera_transform, camera_proj, batch) in batches {
+            for (topology, vertices) in [
+                (PrimitiveTopology::LineList, batch.lines),
+                (PrimitiveTopology::TriangleList, batch.triangles),
+            ] {
+                ColorBatch::draw(
+                    &context,
+                    builder,
+                    (camera_transform, camera_proj),
+                    topology,
+                    &vertices,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    components::{Camera, Trans},
+    world::{renderer_manager::DrawItem, EntityManager},
+    Drawable, Id,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+pub type CameraEntity = (Id, Arc<RwLock<Camera>>, Arc<RwLock<Trans>>);
+
+pub fn camera(em: &EntityManager, camera: Option<Id>) -> Option<CameraEntity> {
+    camera.and_then(|e| {
+        Some((
+            e,
+            em.get_component::<Camera>(e)?,
+            em.get_component::<Trans>(e)?,
+        ))
+    })
+}
+
+pub fn sort<T>(items: Vec<(bool, u32, f32, T)>) -> Vec<T> {
+    let (mut opaque, mut transparent): (Vec<_>, Vec<_>) =
+        items.into_iter().partition(|(opaque, ..)| *opaque);
+
+    opaque.sort_by_key(|(_, l, ..)| *l);
+    transparent.sort_by(|(_, l1, k1, _), (_, l2, k2, _)| l2.cmp(l1).then(k1.total_cmp(k2)));
+
+    opaque
+        .into_iter()
+        .chain(transparent)
+        .map(|(.., item)| item)
+        .collect()
+}
+
+pub fn item<E: 'static>(
+    opaque: bool,
+    layer: u32,
+    key: f32,
+    drawable: Arc<dyn Drawable<E>>,
+    entity: E,
+    camera: CameraEntity,
+) -> DrawItem {
+    (
+        opaque,
+        layer,
+        key,
+        Box::new(move |draw, context, world| drawable.draw(entity, camera, draw, context, world)),
+    )
+}
@@ -0,0 +1,58 @@
+use super::layering;
+use crate::{
+    components::{Mesh2d, Trans},
+    world::renderer_manager::{Draw, DrawItem, Renderer},
+    Context, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+pub struct Mesh2dRenderer;
+
+impl Renderer for Mesh2dRenderer {
+    fn items(
+        &mut self,
+        draw: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<Vec<DrawItem>> {
+        let em = world.read().em.clone();
+        let em = em.read();
+        let Some(camera) = layering::camera(&em, draw.3) else {
+            return Ok(Vec::new());
+        };
+        let bounds = camera.1.read().bounds(&camera.2.read());
+
+        Ok(em
+            .entities()
+            .filter_map(|e| {
+                let m = em.get_component::<Mesh2d>(e)?;
+                let t = em.get_component::<Trans>(e)?;
+                let (opaque, layer, key, d) = {
+                    let m = m.read();
+                    let t = t.read();
+
+                    if !m.mesh.bounds.transform(&t.matrix()).intersects(&bounds) {
+                        return None;
+                    }
+
+                    (
+                        m.blend_mode.is_opaque(),
+                        m.layer,
+                        m.sort_key.unwrap_or(t.position().y),
+                        m.drawable.clone(),
+                    )
+                };
+
+                Some(layering::item(
+                    opaque,
+                    layer,
+                    key,
+                    d,
+                    (e, m, t),
+                    camera.clone(),
+                ))
+            })
+            .collect())
+    }
+}
@@ -1,3 +1,22 @@
+pub mod bitmap_text_renderer;
+pub mod color_batch;
+#[cfg(feature = "debug-draw")]
+pub mod debug_renderer;
+pub mod layering;
+pub mod mesh2d_renderer;
+pub mod particle_renderer;
 pub mod sprite_renderer;
+pub mod text_renderer;
+pub mod tilemap_renderer;
+pub mod ui_renderer;
 
+pub use bitmap_text_renderer::BitmapTextRenderer;
+pub use color_batch::ColorBatch;
+#[cfg(feature = "debug-draw")]
+pub use debug_renderer::DebugRenderer;
+pub use mesh2d_renderer::Mesh2dRenderer;
+pub use particle_renderer::ParticleRenderer;
 pub use sprite_renderer::SpriteRenderer;
+pub use text_renderer::TextRenderer;
+pub use tilemap_renderer::TilemapRenderer;
+pub use ui_renderer::UiRenderer;
@@ -0,0 +1,52 @@
+use super::layering;
+use crate::{
+    components::{ParticleEmitter, Trans},
+    world::renderer_manager::{Draw, DrawItem, Renderer},
+    Context, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+pub struct ParticleRenderer;
+
+impl Renderer for ParticleRenderer {
+    fn items(
+        &mut self,
+        draw: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<Vec<DrawItem>> {
+        let em = world.read().em.clone();
+        let em = em.read();
+        let Some(camera) = layering::camera(&em, draw.3) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(em
+            .entities()
+            .filter_map(|e| {
+                let p = em.get_component::<ParticleEmitter>(e)?;
+                let t = em.get_component::<Trans>(e)?;
+                let (opaque, layer, key, d) = {
+                    let p = p.read();
+
+                    (
+                        p.blend_mode().is_opaque(),
+                        p.layer,
+                        p.sort_key.unwrap_or(t.read().position().y),
+                        p.drawable.clone(),
+                    )
+                };
+
+                Some(layering::item(
+                    opaque,
+                    layer,
+                    key,
+                    d,
+                    (e, p, t),
+                    camera.clone(),
+                ))
+            })
+            .collect())
+    }
+}
@@ -1,6 +1,8 @@
+use super::layering;
 use crate::{
-    components::{Camera, Sprite, Trans},
-    world::renderer_manager::{Draw, Renderer},
+    components::{Sprite, Trans, UiNode},
+    resources::{SpriteStats, Time},
+    world::renderer_manager::{Draw, DrawItem, Renderer},
     Context, World,
 };
 use parking_lot::RwLock;
@@ -9,62 +11,72 @@ use std::sync::Arc;
 pub struct SpriteRenderer;
 
 impl Renderer for SpriteRenderer {
-    fn draw(
+    fn items(
         &mut self,
         draw: &mut Draw,
-        context: Arc<RwLock<Context>>,
+        _: Arc<RwLock<Context>>,
         world: Arc<RwLock<World>>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<DrawItem>> {
         let res = {
             let em = world.read().em.clone();
             let em = em.read();
 
-            em.entities()
-                .find_map(|e| {
-                    Some((
-                        e,
-                        em.get_component::<Camera>(e)?,
-                        em.get_component::<Trans>(e)?,
-                    ))
-                })
-                .map(|c| {
-                    let sprites = {
-                        let mut sprites: Vec<_> = em
-                            .entities()
-                            .filter_map(|e| {
-                                Some((
-                                    e,
-                                    em.get_component::<Sprite>(e)?.clone(),
-                                    em.get_component::<Trans>(e)?.clone(),
-                                ))
-                            })
-                            .collect();
+            layering::camera(&em, draw.3).map(|camera| {
+                let bounds = camera.1.read().bounds(&camera.2.read());
+                let mut culled = 0;
+                let sprites: Vec<_> = em
+                    .entities()
+                    .filter(|e| em.get_component::<UiNode>(*e).is_none())
+                    .filter_map(|e| {
+                        let s = em.get_component::<Sprite>(e)?;
+                        let t = em.get_component::<Trans>(e)?;
+                        let (opaque, layer, key, d) = {
+                            let s = s.read();
+                            let t = t.read();
 
-                        sprites.sort_by(|(_, s1, _), (_, s2, _)| {
-                            s2.read().layer.cmp(&s1.read().layer)
-                        });
+                            if !s.shape.bounds.transform(&t.matrix()).intersects(&bounds) {
+                                culled += 1;
 
-                        sprites
-                    };
+                                return None;
+                            }
 
-                    (c, sprites)
-                })
+                            (
+                                s.blend_mode.is_opaque(),
+                                s.layer,
+                                s.sort_key.unwrap_or(t.position().y),
+                                s.drawable.clone(),
+                            )
+                        };
+
+                        Some(layering::item(
+                            opaque,
+                            layer,
+                            key,
+                            d,
+                            (e, s, t),
+                            camera.clone(),
+                        ))
+                    })
+                    .collect();
+
+                (sprites, culled)
+            })
+        };
+        let Some((sprites, culled)) = res else {
+            return Ok(Vec::new());
         };
+        let res = world.read().res.clone();
+        let res = res.read();
 
-        if let Some(((ce, c, ct), sprites)) = res {
-            for (se, s, t) in sprites {
-                let d = s.read().drawable.clone();
+        if let Some(stats) = res.get::<SpriteStats>() {
+            let frame = res
+                .get::<Time>()
+                .map(|t| t.read().frame())
+                .unwrap_or_default();
 
-                d.draw(
-                    (se, s.clone(), t.clone()),
-                    (ce, c.clone(), ct.clone()),
-                    draw,
-                    context.clone(),
-                    world.clone(),
-                )?;
-            }
+            stats.write().record(frame, sprites.len(), culled);
         }
 
-        Ok(())
+        Ok(sprites)
     }
 }
@@ -0,0 +1,56 @@
+use super::layering;
+use crate::{
+    components::{Text, Trans, UiNode},
+    world::renderer_manager::{Draw, DrawItem, Renderer},
+    Context, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+pub struct TextRenderer;
+
+impl Renderer for TextRenderer {
+    fn items(
+        &mut self,
+        draw: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<Vec<DrawItem>> {
+        let em = world.read().em.clone();
+        let em = em.read();
+        let Some(camera) = layering::camera(&em, draw.3) else {
+            return Ok(Vec::new());
+        };
+        let bounds = camera.1.read().bounds(&camera.2.read());
+
+        Ok(em
+            .entities()
+            .filter(|e| em.get_component::<UiNode>(*e).is_none())
+            .filter_map(|e| {
+                let text = em.get_component::<Text>(e)?;
+                let t = em.get_component::<Trans>(e)?;
+                let text = text.read();
+                let s = text.sprite()?.clone();
+                let key = {
+                    let t = t.read();
+
+                    if !text.bounds().transform(&t.matrix()).intersects(&bounds) {
+                        return None;
+                    }
+
+                    text.sort_key.unwrap_or(t.position().y)
+                };
+                let d = s.read().drawable.clone();
+
+                Some(layering::item(
+                    false,
+                    text.layer,
+                    key,
+                    d,
+                    (e, s, t),
+                    camera.clone(),
+                ))
+            })
+            .collect())
+    }
+}
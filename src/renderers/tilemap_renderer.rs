@@ -0,0 +1,68 @@
+use super::layering;
+use crate::{
+    components::{Tilemap, Trans},
+    world::renderer_manager::{Draw, DrawItem, Renderer},
+    Context, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+pub struct TilemapRenderer;
+
+impl Renderer for TilemapRenderer {
+    fn items(
+        &mut self,
+        draw: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<Vec<DrawItem>> {
+        let em = world.read().em.clone();
+        let em = em.read();
+        let Some(camera) = layering::camera(&em, draw.3) else {
+            return Ok(Vec::new());
+        };
+        let bounds = camera.1.read().bounds(&camera.2.read());
+
+        Ok(em
+            .entities()
+            .filter_map(|e| {
+                let tilemap = em.get_component::<Tilemap>(e)?;
+                let t = em.get_component::<Trans>(e)?;
+                let matrix = t.read().matrix();
+                let chunks = tilemap
+                    .read()
+                    .chunks()
+                    .filter_map(|(_, s)| {
+                        let (opaque, layer, key, d) = {
+                            let s = s.read();
+                            let chunk = s.shape.bounds.transform(&matrix);
+
+                            if !chunk.intersects(&bounds) {
+                                return None;
+                            }
+
+                            (
+                                s.blend_mode.is_opaque(),
+                                s.layer,
+                                (chunk.min.y + chunk.max.y) / 2.0,
+                                s.drawable.clone(),
+                            )
+                        };
+
+                        Some(layering::item(
+                            opaque,
+                            layer,
+                            key,
+                            d,
+                            (e, s.clone(), t.clone()),
+                            camera.clone(),
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+
+                Some(chunks)
+            })
+            .flatten()
+            .collect())
+    }
+}
@@ -0,0 +1,235 @@
+use super::{layering::CameraEntity, ColorBatch};
+use crate::{
+    assets::{mesh::ColorVertex2, shape::Bounds},
+    components::{
+        text::TextAlign,
+        ui::{UiState, Widget},
+        BitmapText, Camera, Sprite, Text, Trans, UiNode,
+    },
+    resources::Ui,
+    world::{
+        renderer_manager::{Draw, Renderer},
+        EntityManager,
+    },
+    Context, Id, World,
+};
+use nalgebra::{Vector2, Vector4};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use vulkano::pipeline::graphics::input_assembly::PrimitiveTopology;
+
+const HOVER_TINT: f32 = 1.2;
+const PRESS_TINT: f32 = 0.8;
+const CARET_WIDTH: f32 = 2.0;
+
+enum Label {
+    Text(Arc<RwLock<Sprite>>),
+    BitmapText(Arc<RwLock<BitmapText>>),
+}
+
+pub struct UiRenderer;
+
+impl UiRenderer {
+    fn quad(rect: Bounds, color: Vector4<f32>) -> [ColorVertex2; 6] {
+        let [a, b, c, d] = rect.corners().map(|p| ColorVertex2::new(p, color));
+
+        [a, b, c, a, c, d]
+    }
+
+    fn tint(color: Vector4<f32>, state: UiState) -> Vector4<f32> {
+        let factor = if state.pressed {
+            PRESS_TINT
+        } else if state.hovered {
+            HOVER_TINT
+        } else {
+            1.0
+        };
+
+        Vector4::new(
+            (color.x * factor).min(1.0),
+            (color.y * factor).min(1.0),
+            (color.z * factor).min(1.0),
+            color.w,
+        )
+    }
+
+    fn label(em: &EntityManager, id: Id) -> Option<(Label, Bounds, TextAlign)> {
+        if let Some(text) = em.get_component::<Text>(id) {
+            let text = text.read();
+
+            return Some((
+                Label::Text(text.sprite()?.clone()),
+                text.bounds(),
+                text.align(),
+            ));
+        }
+
+        let b = em.get_component::<BitmapText>(id)?;
+        let (bounds, align) = {
+            let b = b.read();
+
+            (b.bounds(), b.align())
+        };
+
+        Some((Label::BitmapText(b), bounds, align))
+    }
+
+    fn place(content: Bounds, bounds: Bounds, align: TextAlign) -> Vector2<f32> {
+        let center = (content.min + content.max) / 2.0;
+
+        Vector2::new(
+            match align {
+                TextAlign::Left => content.min.x - bounds.min.x,
+                TextAlign::Center => center.x - (bounds.min.x + bounds.max.x) / 2.0,
+                TextAlign::Right => content.max.x - bounds.max.x,
+            },
+            center.y - (bounds.min.y + bounds.max.y) / 2.0,
+        )
+    }
+}
+
+impl Renderer for UiRenderer {
+    fn overlay(
+        &mut self,
+        draw: &mut Draw,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        let Some(ui) = world.read().res.read().get::<Ui>() else {
+            return Ok(());
+        };
+        let em = world.read().em.clone();
+
+        if let Some(c) = draw.3.and_then(|e| em.read().get_component::<Camera>(e)) {
+            if c.read().target().is_some() {
+                return Ok(());
+            }
+        }
+
+        let viewport = Vector2::from(context.read().viewport.extent);
+        let camera: CameraEntity = (
+            draw.3.unwrap_or_default(),
+            Camera::new(viewport, u32::MAX),
+            Trans::new(viewport / 2.0, 0.0, Vector2::new(1.0, 1.0)),
+        );
+        let (_, c, ct) = &camera;
+        let view = (ct.read().matrix(), c.read().proj());
+        let order = ui.read().order().to_vec();
+
+        for id in order {
+            let (n, sprite, label) = {
+                let em = em.read();
+                let Some(n) = em.get_component::<UiNode>(id) else {
+                    continue;
+                };
+                let n = n.read().clone();
+
+                (n, em.get_component::<Sprite>(id), Self::label(&em, id))
+            };
+            let rect = n.rect();
+            let content = n.content();
+            let mut vertices = Vec::new();
+
+            if let Some(background) = n.background {
+                vertices.extend(Self::quad(rect, Self::tint(background, n.state())));
+            }
+
+            if let Some(Widget::Slider { value, min, max }) = n.widget {
+                let t = ((value - min) / (max - min).max(f32::EPSILON)).clamp(0.0, 1.0);
+                let fill = Bounds::new(
+                    rect.min,
+                    Vector2::new(rect.min.x + (rect.max.x - rect.min.x) * t, rect.max.y),
+                );
+
+                vertices.extend(Self::quad(fill, n.foreground));
+            }
+
+            {
+                let context = context.read();
+
+                ColorBatch::draw(
+                    &context,
+                    draw.1,
+                    view,
+                    PrimitiveTopology::TriangleList,
+                    &vertices,
+                )?;
+            }
+
+            if let Some(s) = sprite {
+                let (drawable, scale) = {
+                    let s = s.read();
+                    let size = s.shape.bounds.max - s.shape.bounds.min;
+                    let scale = if s.nine_slice.is_some() {
+                        Vector2::new(1.0, 1.0)
+                    } else {
+                        (rect.max - rect.min).component_div(&size.map(|v| v.max(f32::EPSILON)))
+                    };
+
+                    (s.drawable.clone(), scale)
+                };
+                let t = Trans::new((rect.min + rect.max) / 2.0, 0.0, scale);
+
+                drawable.draw(
+                    (id, s, t),
+                    camera.clone(),
+                    draw,
+                    context.clone(),
+                    world.clone(),
+                )?;
+            }
+
+            let mut caret = content.min.x;
+
+            if let Some((label, bounds, align)) = label {
+                let position = Self::place(content, bounds, align);
+                let t = Trans::new(position, 0.0, Vector2::new(1.0, 1.0));
+
+                caret = position.x + bounds.max.x;
+
+                match label {
+                    Label::Text(s) => {
+                        let drawable = s.read().drawable.clone();
+
+                        drawable.draw(
+                            (id, s, t),
+                            camera.clone(),
+                            draw,
+                            context.clone(),
+                            world.clone(),
+                        )?;
+                    }
+                    Label::BitmapText(b) => {
+                        let drawable = b.read().drawable.clone();
+
+                        drawable.draw(
+                            (id, b, t),
+                            camera.clone(),
+                            draw,
+                            context.clone(),
+                            world.clone(),
+                        )?;
+                    }
+                }
+            }
+
+            if n.state().focused {
+                let context = context.read();
+                let caret = Bounds::new(
+                    Vector2::new(caret, content.min.y),
+                    Vector2::new(caret + CARET_WIDTH, content.max.y),
+                );
+
+                ColorBatch::draw(
+                    &context,
+                    draw.1,
+                    view,
+                    PrimitiveTopology::TriangleList,
+                    &Self::quad(caret, n.foreground),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use super::{font, DebugBatch, DebugDraw, DebugSpace};
+use crate::assets::mesh::ColorVertex2;
+use nalgebra::{Rotation2, Vector2, Vector4};
+use std::f32::consts::TAU;
+
+const CIRCLE_SEGMENTS: u32 = 32;
+
+impl DebugDraw {
+    pub fn line(
+        &mut self,
+        space: DebugSpace,
+        from: Vector2<f32>,
+        to: Vector2<f32>,
+        color: Vector4<f32>,
+    ) {
+        self.batch_mut(space)
+            .lines
+            .extend([ColorVertex2::new(from, color), ColorVertex2::new(to, color)]);
+    }
+
+    pub fn polyline(
+        &mut self,
+        space: DebugSpace,
+        points: &[Vector2<f32>],
+        closed: bool,
+        color: Vector4<f32>,
+    ) {
+        for w in points.windows(2) {
+            self.line(space, w[0], w[1], color);
+        }
+
+        if let Some((first, last)) = points.first().zip(points.last()).filter(|_| closed) {
+            self.line(space, *last, *first, color);
+        }
+    }
+
+    pub fn rect(
+        &mut self,
+        space: DebugSpace,
+        center: Vector2<f32>,
+        dims: Vector2<f32>,
+        color: Vector4<f32>,
+    ) {
+        let half = dims / 2.0;
+
+        self.polyline(
+            space,
+            &[
+                center + Vector2::new(-half.x, -half.y),
+                center + Vector2::new(half.x, -half.y),
+                center + Vector2::new(half.x, half.y),
+                center + Vector2::new(-half.x, half.y),
+            ],
+            true,
+            color,
+        );
+    }
+
+    pub fn circle(
+        &mut self,
+        space: DebugSpace,
+        center: Vector2<f32>,
+        radius: f32,
+        color: Vector4<f32>,
+    ) {
+        let points: Vec<_> = (0..CIRCLE_SEGMENTS)
+            .map(|i| {
+                let angle = TAU * i as f32 / CIRCLE_SEGMENTS as f32;
+
+                center + Vector2::new(angle.cos(), angle.sin()) * radius
+            })
+            .collect();
+
+        self.polyline(space, &points, true, color);
+    }
+
+    pub fn arrow(
+        &mut self,
+        space: DebugSpace,
+        from: Vector2<f32>,
+        to: Vector2<f32>,
+        color: Vector4<f32>,
+    ) {
+        self.line(space, from, to, color);
+
+        if let Some(dir) = (from - to).try_normalize(f32::EPSILON) {
+            let head = dir * (to - from).norm() * 0.2;
+
+            for angle in [-0.4, 0.4] {
+                self.line(space, to, to + Rotation2::new(angle) * head, color);
+            }
+        }
+    }
+
+    pub fn text(
+        &mut self,
+        space: DebugSpace,
+        position: Vector2<f32>,
+        text: &str,
+        scale: f32,
+        color: Vector4<f32>,
+    ) {
+        let triangles = &mut self.batch_mut(space).triangles;
+        let mut cursor = position;
+
+        for c in text.chars() {
+            if c == '\n' {
+                cursor = Vector2::new(
+                    position.x,
+                    cursor.y + (font::GLYPH_HEIGHT + 1) as f32 * scale,
+                );
+
+                continue;
+            }
+
+            for (x, column) in font::glyph(c).into_iter().enumerate() {
+                for y in (0..font::GLYPH_HEIGHT).filter(|y| column & (1 << y) != 0) {
+                    let min = cursor + Vector2::new(x as f32, y as f32) * scale;
+                    let max = min + Vector2::repeat(scale);
+
+                    triangles.extend(
+                        [
+                            min,
+                            Vector2::new(max.x, min.y),
+                            max,
+                            min,
+                            max,
+                            Vector2::new(min.x, max.y),
+                        ]
+                        .map(|p| ColorVertex2::new(p, color)),
+                    );
+                }
+            }
+
+            cursor.x += (font::GLYPH_WIDTH + 1) as f32 * scale;
+        }
+    }
+
+    fn batch_mut(&mut self, space: DebugSpace) -> &mut DebugBatch {
+        match space {
+            DebugSpace::World => &mut self.world,
+            DebugSpace::Screen => &mut self.screen,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(feature = "debug-draw")]
+pub mod draw;
+#[cfg(feature = "debug-draw")]
+pub mod font;
+#[cfg(not(feature = "debug-draw"))]
+pub mod noop;
+
+use crate::assets::mesh::ColorVertex2;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DebugSpace {
+    #[default]
+    World,
+    Screen,
+}
+
+#[derive(Clone, Default)]
+pub struct DebugBatch {
+    pub lines: Vec<ColorVertex2>,
+    pub triangles: Vec<ColorVertex2>,
+}
+
+impl DebugBatch {
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.triangles.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.lines.clear();
+        self.triangles.clear();
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct DebugDraw {
+    world: DebugBatch,
+    screen: DebugBatch,
+}
+
+impl DebugDraw {
+    pub fn new() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Default::default()))
+    }
+
+    pub fn batch(&self, space: DebugSpace) -> &DebugBatch {
+        match space {
+            DebugSpace::World => &self.world,
+            DebugSpace::Screen => &self.screen,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.world.clear();
+        self.screen.clear();
+    }
+}
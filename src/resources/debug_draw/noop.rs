@@ -0,0 +1,22 @@
+use super::{DebugDraw, DebugSpace};
+use nalgebra::{Vector2, Vector4};
+
+impl DebugDraw {
+    #[inline]
+    pub fn line(&mut self, _: DebugSpace, _: Vector2<f32>, _: Vector2<f32>, _: Vector4<f32>) {}
+
+    #[inline]
+    pub fn polyline(&mut self, _: DebugSpace, _: &[Vector2<f32>], _: bool, _: Vector4<f32>) {}
+
+    #[inline]
+    pub fn rect(&mut self, _: DebugSpace, _: Vector2<f32>, _: Vector2<f32>, _: Vector4<f32>) {}
+
+    #[inline]
+    pub fn circle(&mut self, _: DebugSpace, _: Vector2<f32>, _: f32, _: Vector4<f32>) {}
+
+    #[inline]
+    pub fn arrow(&mut self, _: DebugSpace, _: Vector2<f32>, _: Vector2<f32>, _: Vector4<f32>) {}
+
+    #[inline]
+    pub fn text(&mut self, _: DebugSpace, _: Vector2<f32>, _: &str, _: f32, _: Vector4<f32>) {}
+}
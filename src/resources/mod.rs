@@ -0,0 +1,11 @@
+pub mod debug_draw;
+pub mod post_process;
+pub mod sprite_stats;
+pub mod time;
+pub mod ui;
+
+pub use debug_draw::{DebugDraw, DebugSpace};
+pub use post_process::{PostEffect, PostProcess, Tonemap};
+pub use sprite_stats::SpriteStats;
+pub use time::Time;
+pub use ui::{TextEdit, Ui, UiEvent};
@@ -0,0 +1,31 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    vec2 direction;
+    float radius;
+};
+
+const float weights[5] = float[](0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+
+void main(void) {
+        vec2 offset = direction * radius / vec2(textureSize(sampler2D(tex, s), 0));
+        vec3 c = texture(sampler2D(tex, s), tex_pos).rgb * weights[0];
+
+        for (int i = 1; i < 5; i++) {
+                c += texture(sampler2D(tex, s), tex_pos + offset * float(i)).rgb * weights[i];
+                c += texture(sampler2D(tex, s), tex_pos - offset * float(i)).rgb * weights[i];
+        }
+
+        frag_color = vec4(c, 1.0);
+}
+        ",
+}
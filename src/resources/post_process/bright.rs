@@ -0,0 +1,23 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    float threshold;
+};
+
+void main(void) {
+        vec4 c = texture(sampler2D(tex, s), tex_pos);
+        float l = dot(c.rgb, vec3(0.2126, 0.7152, 0.0722));
+
+        frag_color = vec4(c.rgb * max(l - threshold, 0.0) / max(l, 0.0001), 1.0);
+}
+        ",
+}
@@ -0,0 +1,31 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    float intensity;
+};
+layout(set = 1, binding = 1) uniform sampler2D lut;
+
+void main(void) {
+        vec4 c = texture(sampler2D(tex, s), tex_pos);
+        vec3 v = clamp(c.rgb, 0.0, 1.0);
+        float n = float(textureSize(lut, 0).y);
+        float b = v.b * (n - 1.0);
+        float b0 = floor(b);
+        float b1 = min(b0 + 1.0, n - 1.0);
+        vec2 uv = vec2(v.r * (n - 1.0) + 0.5, v.g * (n - 1.0) + 0.5) / vec2(n * n, n);
+        vec3 g0 = texture(lut, uv + vec2(b0 / n, 0.0)).rgb;
+        vec3 g1 = texture(lut, uv + vec2(b1 / n, 0.0)).rgb;
+
+        frag_color = vec4(mix(c.rgb, mix(g0, g1, b - b0), intensity), c.a);
+}
+        ",
+}
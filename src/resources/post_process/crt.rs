@@ -0,0 +1,39 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    float curvature;
+    float scanlines;
+    float aberration;
+};
+
+void main(void) {
+        vec2 size = vec2(textureSize(sampler2D(tex, s), 0));
+        vec2 uv = tex_pos * 2.0 - 1.0;
+
+        uv = (uv * (1.0 + curvature * dot(uv.yx, uv.yx))) * 0.5 + 0.5;
+
+        if (any(lessThan(uv, vec2(0.0))) || any(greaterThan(uv, vec2(1.0)))) {
+                frag_color = vec4(0.0, 0.0, 0.0, 1.0);
+
+                return;
+        }
+
+        vec2 shift = vec2(aberration / size.x, 0.0);
+        vec4 c = texture(sampler2D(tex, s), uv);
+        float r = texture(sampler2D(tex, s), uv + shift).r;
+        float b = texture(sampler2D(tex, s), uv - shift).b;
+        float line = 1.0 - scanlines * (0.5 - 0.5 * cos(uv.y * size.y * 3.14159265));
+
+        frag_color = vec4(vec3(r, c.g, b) * line, c.a);
+}
+        ",
+}
@@ -0,0 +1,13 @@
+vulkano_shaders::shader! {
+    ty: "vertex",
+    src: r"
+#version 450
+
+layout(location = 0) out vec2 tex_pos;
+
+void main(void) {
+        tex_pos = vec2((gl_VertexIndex << 1) & 2, gl_VertexIndex & 2);
+        gl_Position = vec4(tex_pos * 2.0 - 1.0, 0.0, 1.0);
+}
+        ",
+}
@@ -0,0 +1,95 @@
+pub mod bloom;
+pub mod blur;
+pub mod bright;
+pub mod color_grading;
+pub mod crt;
+pub mod fullscreen;
+pub mod pixelate;
+pub mod post_effect;
+pub mod post_pass;
+pub mod tonemap;
+pub mod vignette;
+
+pub use post_effect::PostEffect;
+pub use post_pass::PostPass;
+
+use crate::{
+    assets::{material::MaterialParam, upload_queue::UploadBuilder},
+    Context,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Tonemap {
+    #[default]
+    None,
+    Reinhard,
+    Aces,
+}
+
+#[derive(Clone)]
+pub struct PostProcess {
+    pub effects: Vec<PostEffect>,
+    pub tonemap: Tonemap,
+    pub exposure: f32,
+    pub enabled: bool,
+}
+
+impl Default for PostProcess {
+    fn default() -> Self {
+        Self {
+            effects: Vec::new(),
+            tonemap: Tonemap::default(),
+            exposure: 1.0,
+            enabled: true,
+        }
+    }
+}
+
+impl PostProcess {
+    pub fn new() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Default::default()))
+    }
+
+    pub fn push(&mut self, effect: PostEffect) {
+        self.effects.push(effect);
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    pub fn apply(
+        &self,
+        context: &Context,
+        builder: &mut UploadBuilder,
+        image_index: u32,
+        time: f32,
+    ) -> anyhow::Result<()> {
+        let pass = PostPass::new(context, time)?;
+        let mut source = 0;
+
+        if self.enabled {
+            for effect in &self.effects {
+                source = effect.apply(&pass, builder, &context.scene, source)?;
+            }
+        }
+
+        pass.draw(
+            builder,
+            context.framebuffers[image_index as usize].clone(),
+            context.scene[source].color.clone(),
+            context.shader("post::tonemap", tonemap::load)?,
+            &[
+                MaterialParam::Float(self.exposure),
+                MaterialParam::Int(self.tonemap as i32),
+            ],
+            &context
+                .overlay
+                .iter()
+                .map(|overlay| pass.texture(overlay))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
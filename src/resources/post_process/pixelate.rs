@@ -0,0 +1,22 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    float size;
+};
+
+void main(void) {
+        vec2 cell = max(size, 1.0) / vec2(textureSize(sampler2D(tex, s), 0));
+
+        frag_color = texture(sampler2D(tex, s), (floor(tex_pos / cell) + 0.5) * cell);
+}
+        ",
+}
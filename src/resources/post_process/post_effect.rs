@@ -0,0 +1,144 @@
+use super::{bloom, blur, bright, color_grading, crt, pixelate, vignette, PostPass};
+use crate::assets::{
+    material::MaterialParam, upload_queue::UploadBuilder, Material, RenderTarget, Texture,
+};
+use nalgebra::{Vector2, Vector4};
+
+#[derive(Clone)]
+pub enum PostEffect {
+    Bloom {
+        threshold: f32,
+        intensity: f32,
+        radius: f32,
+    },
+    ColorGrading {
+        lut: Texture,
+        intensity: f32,
+    },
+    Vignette {
+        color: Vector4<f32>,
+        radius: f32,
+        softness: f32,
+    },
+    Crt {
+        curvature: f32,
+        scanlines: f32,
+        aberration: f32,
+    },
+    Pixelate {
+        size: f32,
+    },
+    Custom(Material),
+}
+
+impl PostEffect {
+    pub fn apply(
+        &self,
+        pass: &PostPass,
+        builder: &mut UploadBuilder,
+        targets: &[RenderTarget],
+        source: usize,
+    ) -> anyhow::Result<usize> {
+        let context = pass.context();
+        let mut others = (0..targets.len()).filter(|i| *i != source);
+        let (Some(a), Some(b)) = (others.next(), others.next()) else {
+            return Ok(source);
+        };
+        let (shader, params, textures) = match self {
+            Self::Bloom {
+                threshold,
+                intensity,
+                radius,
+            } => {
+                let blur = context.shader("post::blur", blur::load)?;
+
+                pass.draw(
+                    builder,
+                    targets[a].framebuffer.clone(),
+                    targets[source].color.clone(),
+                    context.shader("post::bright", bright::load)?,
+                    &[MaterialParam::Float(*threshold)],
+                    &[],
+                )?;
+
+                for (from, to, direction) in [(a, b, Vector2::x()), (b, a, Vector2::y())] {
+                    pass.draw(
+                        builder,
+                        targets[to].framebuffer.clone(),
+                        targets[from].color.clone(),
+                        blur.clone(),
+                        &[
+                            MaterialParam::Vec2(direction),
+                            MaterialParam::Float(*radius),
+                        ],
+                        &[],
+                    )?;
+                }
+
+                (
+                    context.shader("post::bloom", bloom::load)?,
+                    vec![MaterialParam::Float(*intensity)],
+                    vec![pass.texture(&targets[a])],
+                )
+            }
+            Self::ColorGrading { lut, intensity } => (
+                context.shader("post::color_grading", color_grading::load)?,
+                vec![MaterialParam::Float(*intensity)],
+                vec![lut.clone()],
+            ),
+            Self::Vignette {
+                color,
+                radius,
+                softness,
+            } => (
+                context.shader("post::vignette", vignette::load)?,
+                vec![
+                    MaterialParam::Vec4(*color),
+                    MaterialParam::Float(*radius),
+                    MaterialParam::Float(*softness),
+                ],
+                Vec::new(),
+            ),
+            Self::Crt {
+                curvature,
+                scanlines,
+                aberration,
+            } => (
+                context.shader("post::crt", crt::load)?,
+                vec![
+                    MaterialParam::Float(*curvature),
+                    MaterialParam::Float(*scanlines),
+                    MaterialParam::Float(*aberration),
+                ],
+                Vec::new(),
+            ),
+            Self::Pixelate { size } => (
+                context.shader("post::pixelate", pixelate::load)?,
+                vec![MaterialParam::Float(*size)],
+                Vec::new(),
+            ),
+            Self::Custom(material) => {
+                let Some(shader) = material.shader.get() else {
+                    return Ok(source);
+                };
+
+                (
+                    shader.entry_point,
+                    material.params.iter().map(|(_, p)| *p).collect(),
+                    material.textures.clone(),
+                )
+            }
+        };
+
+        pass.draw(
+            builder,
+            targets[b].framebuffer.clone(),
+            targets[source].color.clone(),
+            shader,
+            &params,
+            &textures,
+        )?;
+
+        Ok(b)
+    }
+}
@@ -0,0 +1,246 @@
+use super::fullscreen;
+use crate::{
+    assets::{
+        material::MaterialParam, upload_queue::UploadBuilder, RenderTarget, SamplerPreset, Texture,
+    },
+    Context,
+};
+use std::sync::Arc;
+use vulkano::{
+    buffer::{
+        allocator::{SubbufferAllocator, SubbufferAllocatorCreateInfo},
+        BufferUsage, Subbuffer,
+    },
+    command_buffer::RenderPassBeginInfo,
+    descriptor_set::{PersistentDescriptorSet, WriteDescriptorSet},
+    format::ClearValue,
+    image::{sampler::Sampler, view::ImageView, ImageAspects},
+    memory::allocator::MemoryTypeFilter,
+    pipeline::{
+        graphics::{
+            color_blend::{ColorBlendAttachmentState, ColorBlendState},
+            input_assembly::InputAssemblyState,
+            multisample::MultisampleState,
+            rasterization::RasterizationState,
+            vertex_input::VertexInputState,
+            viewport::{Viewport, ViewportState},
+            GraphicsPipelineCreateInfo,
+        },
+        layout::PipelineDescriptorSetLayoutCreateInfo,
+        DynamicState, GraphicsPipeline, Pipeline, PipelineBindPoint, PipelineLayout,
+        PipelineShaderStageCreateInfo,
+    },
+    render_pass::{AttachmentLoadOp, Framebuffer, RenderPass, Subpass},
+    shader::EntryPoint,
+    DeviceSize, Handle, VulkanObject,
+};
+
+pub struct PostPass<'a> {
+    context: &'a Context,
+    sampler: Arc<Sampler>,
+    uniform_allocator: SubbufferAllocator,
+    time: f32,
+}
+
+impl<'a> PostPass<'a> {
+    pub fn new(context: &'a Context, time: f32) -> anyhow::Result<Self> {
+        Ok(Self {
+            context,
+            sampler: context.sampler(SamplerPreset::LINEAR)?,
+            uniform_allocator: SubbufferAllocator::new(
+                context.memory_allocator.clone(),
+                SubbufferAllocatorCreateInfo {
+                    buffer_usage: BufferUsage::UNIFORM_BUFFER,
+                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                        | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                    ..Default::default()
+                },
+            ),
+            time,
+        })
+    }
+
+    pub fn context(&self) -> &Context {
+        self.context
+    }
+
+    pub fn texture(&self, target: &RenderTarget) -> Texture {
+        target.texture(self.sampler.clone())
+    }
+
+    pub fn draw(
+        &self,
+        builder: &mut UploadBuilder,
+        framebuffer: Arc<Framebuffer>,
+        source: Arc<ImageView>,
+        fragment: EntryPoint,
+        params: &[MaterialParam],
+        textures: &[Texture],
+    ) -> anyhow::Result<()> {
+        let pipeline = self.pipeline(framebuffer.render_pass().clone(), fragment)?;
+        let extent = framebuffer.extent();
+        let clear_values = framebuffer
+            .render_pass()
+            .attachments()
+            .iter()
+            .map(|a| {
+                (a.load_op == AttachmentLoadOp::Clear).then(|| {
+                    if a.format.aspects().intersects(ImageAspects::DEPTH) {
+                        ClearValue::from(1f32)
+                    } else {
+                        ClearValue::from([0.0f32; 4])
+                    }
+                })
+            })
+            .collect();
+        let layouts = pipeline.layout().set_layouts();
+        let mut sets = Vec::new();
+
+        if let Some(layout) = layouts.first() {
+            let mut writes = vec![
+                WriteDescriptorSet::sampler(0, self.sampler.clone()),
+                WriteDescriptorSet::image_view(1, source),
+            ];
+
+            if layout.bindings().contains_key(&2) {
+                writes.push(WriteDescriptorSet::buffer(
+                    2,
+                    self.uniform(&[
+                        MaterialParam::Vec2([extent[0] as f32, extent[1] as f32].into()),
+                        MaterialParam::Float(self.time),
+                    ])?,
+                ));
+            }
+
+            sets.push((
+                0,
+                PersistentDescriptorSet::new(
+                    &self.context.descriptor_set_allocator,
+                    layout.clone(),
+                    writes,
+                    [],
+                )?,
+            ));
+        }
+
+        if let Some(layout) = layouts.get(1).filter(|l| !l.bindings().is_empty()) {
+            let mut writes = Vec::new();
+
+            if !params.is_empty() {
+                writes.push(WriteDescriptorSet::buffer(0, self.uniform(params)?));
+            }
+
+            for (i, texture) in textures.iter().enumerate() {
+                writes.push(WriteDescriptorSet::image_view_sampler(
+                    i as u32 + 1,
+                    texture.image.clone(),
+                    texture.sampler.clone(),
+                ));
+            }
+
+            sets.push((
+                1,
+                PersistentDescriptorSet::new(
+                    &self.context.descriptor_set_allocator,
+                    layout.clone(),
+                    writes,
+                    [],
+                )?,
+            ));
+        }
+
+        builder
+            .begin_render_pass(
+                RenderPassBeginInfo {
+                    clear_values,
+                    ..RenderPassBeginInfo::framebuffer(framebuffer)
+                },
+                Default::default(),
+            )?
+            .set_viewport(
+                0,
+                [Viewport {
+                    offset: [0.0, 0.0],
+                    extent: [extent[0] as f32, extent[1] as f32],
+                    depth_range: 0.0..=1.0,
+                }]
+                .into_iter()
+                .collect(),
+            )?
+            .bind_pipeline_graphics(pipeline.clone())?;
+
+        for (index, set) in sets {
+            builder.bind_descriptor_sets(
+                PipelineBindPoint::Graphics,
+                pipeline.layout().clone(),
+                index,
+                set,
+            )?;
+        }
+
+        builder
+            .draw(3, 1, 0, 0)?
+            .end_render_pass(Default::default())?;
+
+        Ok(())
+    }
+
+    fn uniform(&self, params: &[MaterialParam]) -> anyhow::Result<Subbuffer<[u8]>> {
+        let bytes = MaterialParam::uniform_bytes(params);
+        let subbuffer = self
+            .uniform_allocator
+            .allocate_slice(bytes.len() as DeviceSize)?;
+
+        subbuffer.write()?.copy_from_slice(&bytes);
+
+        Ok(subbuffer)
+    }
+
+    fn pipeline(
+        &self,
+        render_pass: Arc<RenderPass>,
+        fragment: EntryPoint,
+    ) -> anyhow::Result<Arc<GraphicsPipeline>> {
+        let key = ("post", render_pass.handle().as_raw());
+
+        self.context.shader_pipeline(key, &[&fragment.clone()], || {
+            let context = self.context;
+            let vertex = context.shader("post::fullscreen", fullscreen::load)?;
+            let stages = [
+                PipelineShaderStageCreateInfo::new(vertex),
+                PipelineShaderStageCreateInfo::new(fragment),
+            ];
+            let layout = PipelineLayout::new(
+                context.device.clone(),
+                PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+                    .into_pipeline_layout_create_info(context.device.clone())?,
+            )?;
+            let subpass = Subpass::from(render_pass, 0).unwrap();
+
+            Ok(GraphicsPipeline::new(
+                context.device.clone(),
+                None,
+                GraphicsPipelineCreateInfo {
+                    stages: stages.into_iter().collect(),
+                    vertex_input_state: Some(VertexInputState::default()),
+                    input_assembly_state: Some(InputAssemblyState::default()),
+                    viewport_state: Some(ViewportState::default()),
+                    rasterization_state: Some(RasterizationState::default()),
+                    depth_stencil_state: subpass
+                        .subpass_desc()
+                        .depth_stencil_attachment
+                        .as_ref()
+                        .map(|_| Default::default()),
+                    multisample_state: Some(MultisampleState::default()),
+                    color_blend_state: Some(ColorBlendState::with_attachment_states(
+                        subpass.num_color_attachments(),
+                        ColorBlendAttachmentState::default(),
+                    )),
+                    dynamic_state: [DynamicState::Viewport].into_iter().collect(),
+                    subpass: Some(subpass.into()),
+                    ..GraphicsPipelineCreateInfo::layout(layout)
+                },
+            )?)
+        })
+    }
+}
@@ -0,0 +1,37 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    float exposure;
+    int mode;
+};
+layout(set = 1, binding = 1) uniform sampler2D overlay;
+
+vec3 aces(vec3 x) {
+        return clamp((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14), 0.0, 1.0);
+}
+
+void main(void) {
+        vec4 c = texture(sampler2D(tex, s), tex_pos);
+        vec3 v = c.rgb * exposure;
+
+        if (mode == 1) {
+                v = v / (1.0 + v);
+        } else if (mode == 2) {
+                v = aces(v);
+        }
+
+        vec4 o = texture(overlay, tex_pos);
+
+        frag_color = vec4(v * (1.0 - o.a) + o.rgb, max(c.a, o.a));
+}
+        ",
+}
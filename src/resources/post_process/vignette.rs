@@ -0,0 +1,25 @@
+vulkano_shaders::shader! {
+    ty: "fragment",
+    src: r"
+#version 450
+
+layout(location = 0) in vec2 tex_pos;
+
+layout(location = 0) out vec4 frag_color;
+
+layout(set = 0, binding = 0) uniform sampler s;
+layout(set = 0, binding = 1) uniform texture2D tex;
+layout(set = 1, binding = 0) uniform Params {
+    vec4 color;
+    float radius;
+    float softness;
+};
+
+void main(void) {
+        vec4 c = texture(sampler2D(tex, s), tex_pos);
+        float v = smoothstep(radius, radius - softness, length(tex_pos - 0.5));
+
+        frag_color = vec4(mix(color.rgb, c.rgb, mix(1.0, v, color.a)), c.a);
+}
+        ",
+}
@@ -0,0 +1,26 @@
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone, Default)]
+pub struct SpriteStats {
+    pub frame: u64,
+    pub drawn: usize,
+    pub culled: usize,
+}
+
+impl SpriteStats {
+    pub fn new() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Default::default()))
+    }
+
+    pub fn record(&mut self, frame: u64, drawn: usize, culled: usize) {
+        if self.frame != frame {
+            self.frame = frame;
+            self.drawn = 0;
+            self.culled = 0;
+        }
+
+        self.drawn += drawn;
+        self.culled += culled;
+    }
+}
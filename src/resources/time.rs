@@ -0,0 +1,46 @@
+use parking_lot::RwLock;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone)]
+pub struct Time {
+    start: Instant,
+    last: Instant,
+    delta: Duration,
+    frame: u64,
+}
+
+impl Time {
+    pub fn new() -> Arc<RwLock<Self>> {
+        let now = Instant::now();
+
+        Arc::new(RwLock::new(Self {
+            start: now,
+            last: now,
+            delta: Duration::ZERO,
+            frame: 0,
+        }))
+    }
+
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.last - self.start
+    }
+
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+
+        self.delta = now - self.last;
+        self.last = now;
+        self.frame += 1;
+    }
+}
@@ -0,0 +1,117 @@
+use crate::Id;
+use nalgebra::Vector2;
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::{
+    event::{ElementState, MouseButton, WindowEvent},
+    keyboard::{Key, NamedKey},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiEvent {
+    Clicked(Id),
+    ValueChanged(Id, f32),
+    TextChanged(Id, String),
+    Submitted(Id, String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextEdit {
+    Insert(char),
+    Erase,
+}
+
+#[derive(Clone, Default)]
+pub struct Ui {
+    pub(crate) cursor: Option<Vector2<f32>>,
+    pub(crate) down: bool,
+    pub(crate) pressed: bool,
+    pub(crate) released: bool,
+    pub(crate) edits: Vec<TextEdit>,
+    pub(crate) submit: bool,
+    pub(crate) hovered: Option<Id>,
+    pub(crate) focused: Option<Id>,
+    pub(crate) active: Option<Id>,
+    pub(crate) order: Vec<Id>,
+    pub(crate) events: Vec<UiEvent>,
+}
+
+impl Ui {
+    pub fn new() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Default::default()))
+    }
+
+    pub fn cursor(&self) -> Option<Vector2<f32>> {
+        self.cursor
+    }
+
+    pub fn hovered(&self) -> Option<Id> {
+        self.hovered
+    }
+
+    pub fn focused(&self) -> Option<Id> {
+        self.focused
+    }
+
+    pub fn set_focus(&mut self, focused: Option<Id>) {
+        self.focused = focused;
+    }
+
+    pub fn is_captured(&self) -> bool {
+        self.hovered.is_some() || self.active.is_some()
+    }
+
+    pub fn order(&self) -> &[Id] {
+        &self.order
+    }
+
+    pub fn events(&self) -> &[UiEvent] {
+        &self.events
+    }
+
+    pub fn handle(&mut self, event: &WindowEvent) {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor = Some(Vector2::new(position.x as f32, position.y as f32));
+            }
+            WindowEvent::CursorLeft { .. } => self.cursor = None,
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => match state {
+                ElementState::Pressed => {
+                    self.down = true;
+                    self.pressed = true;
+                }
+                ElementState::Released => {
+                    self.down = false;
+                    self.released = true;
+                }
+            },
+            WindowEvent::KeyboardInput { event, .. } if event.state.is_pressed() => {
+                match &event.logical_key {
+                    Key::Named(NamedKey::Backspace) => self.edits.push(TextEdit::Erase),
+                    Key::Named(NamedKey::Enter) => self.submit = true,
+                    _ => {
+                        if let Some(text) = &event.text {
+                            self.edits.extend(
+                                text.chars()
+                                    .filter(|c| !c.is_control())
+                                    .map(TextEdit::Insert),
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn end_frame(&mut self) {
+        self.pressed = false;
+        self.released = false;
+        self.edits.clear();
+        self.submit = false;
+    }
+}
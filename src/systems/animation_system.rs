@@ -0,0 +1,60 @@
+use crate::{
+    components::{Animator, Sprite},
+    resources::Time,
+    world::system_manager::System,
+    Context, Control, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::event::{Event, WindowEvent};
+
+pub struct AnimationSystem;
+
+impl System for AnimationSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let (em, delta) = {
+                let world = world.read();
+                let delta = world
+                    .res
+                    .read()
+                    .get::<Time>()
+                    .map(|t| t.read().delta())
+                    .unwrap_or_default();
+
+                (world.em.clone(), delta)
+            };
+            let em = em.read();
+
+            for e in em.entities() {
+                let Some((a, s)) = em
+                    .get_component::<Animator>(e)
+                    .and_then(|a| Some((a, em.get_component::<Sprite>(e)?)))
+                else {
+                    continue;
+                };
+                let mut a = a.write();
+
+                a.advance(delta);
+
+                if let Some((texture, region)) = a.frame() {
+                    let mut s = s.write();
+
+                    s.texture = texture;
+                    s.region = region;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
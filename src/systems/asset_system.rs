@@ -0,0 +1,29 @@
+use crate::{assets::AssetServer, world::system_manager::System, Context, Control, World};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::event::{Event, WindowEvent};
+
+pub struct AssetSystem;
+
+impl System for AssetSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let server = world.read().res.read().get::<AssetServer>();
+
+            if let Some(server) = server {
+                server.write().update(context);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    components::{Camera, CameraBounds, CameraFollow, CameraShake, Trans},
+    resources::Time,
+    world::system_manager::System,
+    Context, Control, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::event::{Event, WindowEvent};
+
+pub struct CameraSystem;
+
+impl System for CameraSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        _: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let (em, delta) = {
+                let world = world.read();
+                let delta = world
+                    .res
+                    .read()
+                    .get::<Time>()
+                    .map(|t| t.read().delta().as_secs_f32())
+                    .unwrap_or_default();
+
+                (world.em.clone(), delta)
+            };
+            let em = em.read();
+
+            for e in em.entities() {
+                let Some((c, t)) = em
+                    .get_component::<Camera>(e)
+                    .and_then(|c| Some((c, em.get_component::<Trans>(e)?)))
+                else {
+                    continue;
+                };
+                let shake = em.get_component::<CameraShake>(e);
+                let (mut position, mut rotation) = {
+                    let t = t.read();
+
+                    (t.position(), t.rotation())
+                };
+
+                if let Some(s) = &shake {
+                    let s = s.read();
+
+                    position -= s.offset();
+                    rotation -= s.angle();
+                }
+
+                if let Some(f) = em.get_component::<CameraFollow>(e) {
+                    let f = f.read();
+
+                    if let Some(target) = em.get_component::<Trans>(f.target) {
+                        let target = target.read().position();
+
+                        position = f.step(position, target, delta);
+                    }
+                }
+
+                if let Some(b) = em.get_component::<CameraBounds>(e) {
+                    position = b.read().clamp(position, c.read().view());
+                }
+
+                if let Some(s) = &shake {
+                    let mut s = s.write();
+
+                    s.step(delta);
+
+                    position += s.offset();
+                    rotation += s.angle();
+                }
+
+                let mut t = t.write();
+
+                t.set_position(position);
+                t.set_rotation(rotation);
+            }
+        }
+
+        Ok(())
+    }
+}
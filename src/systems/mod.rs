@@ -0,0 +1,15 @@
+pub mod animation_system;
+pub mod asset_system;
+pub mod camera_system;
+pub mod particle_system;
+pub mod text_system;
+pub mod tilemap_system;
+pub mod ui_system;
+
+pub use animation_system::AnimationSystem;
+pub use asset_system::AssetSystem;
+pub use camera_system::CameraSystem;
+pub use particle_system::ParticleSystem;
+pub use text_system::TextSystem;
+pub use tilemap_system::TilemapSystem;
+pub use ui_system::UiSystem;
@@ -0,0 +1,51 @@
+use crate::{
+    components::{ParticleEmitter, Trans},
+    resources::Time,
+    world::system_manager::System,
+    Context, Control, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::event::{Event, WindowEvent};
+
+pub struct ParticleSystem;
+
+impl System for ParticleSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let (em, delta) = {
+                let world = world.read();
+                let delta = world
+                    .res
+                    .read()
+                    .get::<Time>()
+                    .map(|t| t.read().delta())
+                    .unwrap_or_default();
+
+                (world.em.clone(), delta)
+            };
+            let em = em.read();
+            let context = context.read();
+
+            for e in em.entities() {
+                if let Some((p, t)) = em
+                    .get_component::<ParticleEmitter>(e)
+                    .zip(em.get_component::<Trans>(e))
+                {
+                    p.write().update(&context, delta, &t.read())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
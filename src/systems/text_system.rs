@@ -0,0 +1,33 @@
+use crate::{components::Text, world::system_manager::System, Context, Control, World};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::event::{Event, WindowEvent};
+
+pub struct TextSystem;
+
+impl System for TextSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let em = world.read().em.clone();
+            let em = em.read();
+            let context = context.read();
+
+            for e in em.entities() {
+                if let Some(t) = em.get_component::<Text>(e) {
+                    t.write().update(&context)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    components::Tilemap, resources::Time, world::system_manager::System, Context, Control, World,
+};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use winit::event::{Event, WindowEvent};
+
+pub struct TilemapSystem;
+
+impl System for TilemapSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        if let Event::WindowEvent {
+            event: WindowEvent::RedrawRequested,
+            ..
+        } = control.read().event
+        {
+            let (em, delta) = {
+                let world = world.read();
+                let delta = world
+                    .res
+                    .read()
+                    .get::<Time>()
+                    .map(|t| t.read().delta())
+                    .unwrap_or_default();
+
+                (world.em.clone(), delta)
+            };
+            let em = em.read();
+            let context = context.read();
+
+            for e in em.entities() {
+                if let Some(t) = em.get_component::<Tilemap>(e) {
+                    let mut t = t.write();
+
+                    t.animate(delta);
+                    t.update(&context)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,395 @@
+use crate::{
+    assets::shape::Bounds,
+    components::{
+        sprite::NineSlice,
+        ui::{Align, Direction, Justify, Size, Widget},
+        BitmapText, Sprite, Text, UiNode,
+    },
+    resources::{TextEdit, Ui, UiEvent},
+    world::{system_manager::System, EntityManager},
+    Context, Control, Id, World,
+};
+use nalgebra::Vector2;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+use winit::event::{Event, WindowEvent};
+
+pub struct UiSystem;
+
+impl System for UiSystem {
+    fn update(
+        &mut self,
+        control: Arc<RwLock<Control>>,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        let Some(ui) = world.read().res.read().get::<Ui>() else {
+            return Ok(());
+        };
+
+        match &control.read().event {
+            Event::WindowEvent {
+                event: WindowEvent::RedrawRequested,
+                ..
+            } => {
+                let em = world.read().em.clone();
+                let em = em.read();
+                let context = context.read();
+                let mut ui = ui.write();
+                let viewport = Vector2::from(context.viewport.extent);
+                let mut layout = Layout::new(&em);
+
+                layout.run(viewport);
+
+                ui.order = layout.order();
+
+                Self::interact(&layout.nodes, &mut ui);
+                Self::sync(&em, &context, &layout.nodes, &ui)?;
+
+                ui.end_frame();
+            }
+            Event::WindowEvent { event, .. } => ui.write().handle(event),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl UiSystem {
+    fn interact(nodes: &HashMap<Id, Arc<RwLock<UiNode>>>, ui: &mut Ui) {
+        ui.events.clear();
+
+        let hovered = ui.cursor.and_then(|cursor| {
+            ui.order.iter().rev().copied().find(|id| {
+                let n = nodes[id].read();
+
+                n.widget.is_some() && n.rect.contains(cursor)
+            })
+        });
+
+        ui.hovered = hovered;
+
+        if ui.pressed {
+            ui.active = hovered;
+            ui.focused = hovered
+                .filter(|id| matches!(nodes[id].read().widget, Some(Widget::TextInput { .. })));
+        }
+
+        if let Some((id, cursor)) = ui
+            .active
+            .filter(|id| ui.down && nodes.contains_key(id))
+            .zip(ui.cursor)
+        {
+            let mut n = nodes[&id].write();
+            let rect = n.rect;
+
+            if let Some(Widget::Slider { value, min, max }) = &mut n.widget {
+                let t = ((cursor.x - rect.min.x) / (rect.max.x - rect.min.x).max(f32::EPSILON))
+                    .clamp(0.0, 1.0);
+                let next = *min + (*max - *min) * t;
+
+                if next != *value {
+                    *value = next;
+
+                    ui.events.push(UiEvent::ValueChanged(id, next));
+                }
+            }
+        }
+
+        if ui.released {
+            if let Some(id) = ui.active.take().filter(|id| Some(*id) == hovered) {
+                ui.events.push(UiEvent::Clicked(id));
+            }
+        }
+
+        if let Some(id) = ui.focused.filter(|id| nodes.contains_key(id)) {
+            if let Some(Widget::TextInput { text, max_len }) = &mut nodes[&id].write().widget {
+                let before = text.clone();
+
+                for edit in &ui.edits {
+                    match edit {
+                        TextEdit::Insert(c) => {
+                            if max_len.is_none_or(|m| text.chars().count() < m) {
+                                text.push(*c);
+                            }
+                        }
+                        TextEdit::Erase => {
+                            text.pop();
+                        }
+                    }
+                }
+
+                if *text != before {
+                    ui.events.push(UiEvent::TextChanged(id, text.clone()));
+                }
+
+                if ui.submit {
+                    ui.events.push(UiEvent::Submitted(id, text.clone()));
+                }
+            }
+        }
+
+        for (id, n) in nodes {
+            let mut n = n.write();
+
+            n.state.hovered = hovered == Some(*id);
+            n.state.pressed = ui.active == Some(*id) && ui.down;
+            n.state.focused = ui.focused == Some(*id);
+        }
+    }
+
+    fn sync(
+        em: &EntityManager,
+        context: &Context,
+        nodes: &HashMap<Id, Arc<RwLock<UiNode>>>,
+        ui: &Ui,
+    ) -> anyhow::Result<()> {
+        for id in ui.order() {
+            let n = nodes[id].read();
+            let size = n.rect.max - n.rect.min;
+
+            if let Some(s) = em.get_component::<Sprite>(*id) {
+                let mut s = s.write();
+
+                if let Some(nine_slice) = s
+                    .nine_slice
+                    .as_ref()
+                    .map(|(ns, _)| *ns)
+                    .filter(|ns| ns.size != size)
+                {
+                    s.set_nine_slice(context, Some(NineSlice { size, ..nine_slice }))?;
+                }
+            }
+
+            if let Some((Widget::TextInput { text, .. }, t)) =
+                n.widget.as_ref().zip(em.get_component::<Text>(*id))
+            {
+                t.write().set_text(text.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Layout<'a> {
+    em: &'a EntityManager,
+    nodes: HashMap<Id, Arc<RwLock<UiNode>>>,
+    children: HashMap<Option<Id>, Vec<Id>>,
+    measured: HashMap<Id, Vector2<f32>>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(em: &'a EntityManager) -> Self {
+        let nodes: HashMap<_, _> = em
+            .entities()
+            .filter_map(|e| Some((e, em.get_component::<UiNode>(e)?)))
+            .collect();
+        let mut children: HashMap<_, Vec<_>> = HashMap::new();
+
+        for (id, n) in &nodes {
+            let n = n.read();
+
+            if n.visible && n.parent.is_none_or(|p| nodes.contains_key(&p)) {
+                children.entry(n.parent).or_default().push((n.order, *id));
+            }
+        }
+
+        Self {
+            em,
+            children: children
+                .into_iter()
+                .map(|(parent, mut c)| {
+                    c.sort();
+
+                    (parent, c.into_iter().map(|(_, id)| id).collect())
+                })
+                .collect(),
+            nodes,
+            measured: HashMap::new(),
+        }
+    }
+
+    fn run(&mut self, viewport: Vector2<f32>) {
+        let roots = self.children(None);
+
+        for &root in &roots {
+            self.measure(root);
+        }
+
+        for root in roots {
+            self.overlay(root, Bounds::new(Vector2::zeros(), viewport));
+        }
+    }
+
+    fn order(&self) -> Vec<Id> {
+        let mut order = Vec::new();
+        let mut stack: Vec<_> = self.children(None).into_iter().rev().collect();
+
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.children(Some(id)).into_iter().rev());
+        }
+
+        order
+    }
+
+    fn children(&self, parent: Option<Id>) -> Vec<Id> {
+        self.children.get(&parent).cloned().unwrap_or_default()
+    }
+
+    fn measure(&mut self, id: Id) -> Vector2<f32> {
+        let style = self.nodes[&id].read().style;
+        let outer: Vec<_> = self
+            .children(Some(id))
+            .into_iter()
+            .map(|c| self.measure(c) + self.nodes[&c].read().style.margin.sum())
+            .collect();
+        let gaps = style.gap * outer.len().saturating_sub(1) as f32;
+        let sum = outer.iter().sum::<Vector2<f32>>();
+        let max = outer.iter().fold(Vector2::zeros(), |m, s| s.sup(&m));
+        let content = match style.direction {
+            Direction::Row => Vector2::new(sum.x + gaps, max.y),
+            Direction::Column => Vector2::new(max.x, sum.y + gaps),
+            Direction::Overlay => max,
+        }
+        .sup(&self.content_size(id));
+        let intrinsic = content + style.padding.sum();
+        let mut size = intrinsic;
+
+        for (axis, s) in style.size().into_iter().enumerate() {
+            if let Size::Px(v) = s {
+                size[axis] = v;
+            }
+        }
+
+        self.measured.insert(id, size);
+
+        size
+    }
+
+    fn content_size(&self, id: Id) -> Vector2<f32> {
+        let text = self.em.get_component::<Text>(id).map(|t| t.read().bounds());
+        let bitmap_text = self
+            .em
+            .get_component::<BitmapText>(id)
+            .map(|t| t.read().bounds());
+
+        text.into_iter()
+            .chain(bitmap_text)
+            .map(|b| b.max - b.min)
+            .fold(Vector2::zeros(), |m, s| s.sup(&m))
+    }
+
+    fn resolve(&self, id: Id, axis: usize, available: f32) -> f32 {
+        match self.nodes[&id].read().style.size()[axis] {
+            Size::Auto => self.measured[&id][axis],
+            Size::Px(v) => v,
+            Size::Percent(p) => available * p / 100.0,
+            Size::Fill(_) => available,
+        }
+    }
+
+    fn overlay(&mut self, id: Id, content: Bounds) {
+        let style = self.nodes[&id].read().style;
+        let available = content.max - content.min - style.margin.sum();
+        let size = Vector2::new(
+            self.resolve(id, 0, available.x),
+            self.resolve(id, 1, available.y),
+        );
+        let position = content.min
+            + (available - size).component_mul(&style.anchor)
+            + style.margin.start()
+            + style.offset;
+
+        self.arrange(id, Bounds::new(position, position + size));
+    }
+
+    fn arrange(&mut self, id: Id, rect: Bounds) {
+        let (style, content) = {
+            let mut n = self.nodes[&id].write();
+
+            n.rect = rect;
+
+            (n.style, n.content())
+        };
+        let size = (content.max - content.min).map(|v| v.max(0.0));
+        let children = self.children(Some(id));
+        let (main, cross) = match style.direction {
+            Direction::Row => (0, 1),
+            Direction::Column => (1, 0),
+            Direction::Overlay => {
+                for c in children {
+                    self.overlay(c, content);
+                }
+
+                return;
+            }
+        };
+        let sizes: Vec<_> = children
+            .iter()
+            .map(|&c| {
+                let child = self.nodes[&c].read().style;
+                let margin = child.margin.sum();
+                let weight = match child.size()[main] {
+                    Size::Fill(w) => w,
+                    _ => 0.0,
+                };
+                let main_size = if weight > 0.0 {
+                    0.0
+                } else {
+                    self.resolve(c, main, size[main])
+                };
+                let available = size[cross] - margin[cross];
+                let cross_size = match child.size()[cross] {
+                    Size::Auto if style.align == Align::Stretch => available,
+                    _ => self.resolve(c, cross, available),
+                };
+
+                (main_size, cross_size, weight, child)
+            })
+            .collect();
+        let used = sizes
+            .iter()
+            .map(|(m, _, _, child)| m + child.margin.sum()[main])
+            .sum::<f32>()
+            + style.gap * children.len().saturating_sub(1) as f32;
+        let remaining = (size[main] - used).max(0.0);
+        let weights = sizes.iter().map(|(_, _, w, _)| w).sum::<f32>();
+        let free = if weights > 0.0 { 0.0 } else { remaining };
+        let (mut cursor, spacing) = match style.justify {
+            Justify::Start => (0.0, style.gap),
+            Justify::Center => (free / 2.0, style.gap),
+            Justify::End => (free, style.gap),
+            Justify::SpaceBetween => (
+                0.0,
+                style.gap + free / children.len().saturating_sub(1).max(1) as f32,
+            ),
+        };
+
+        for (c, (main_size, cross_size, weight, child)) in children.into_iter().zip(sizes) {
+            let mut child_size = Vector2::zeros();
+            let mut position = content.min + child.offset;
+            let available = size[cross] - child.margin.sum()[cross];
+
+            child_size[main] = if weight > 0.0 {
+                remaining * weight / weights
+            } else {
+                main_size
+            };
+            child_size[cross] = cross_size;
+            position[main] += cursor + child.margin.start()[main];
+            position[cross] += child.margin.start()[cross]
+                + match style.align {
+                    Align::Start | Align::Stretch => 0.0,
+                    Align::Center => (available - cross_size) / 2.0,
+                    Align::End => available - cross_size,
+                };
+
+            self.arrange(c, Bounds::new(position, position + child_size));
+
+            cursor += child_size[main] + child.margin.sum()[main] + spacing;
+        }
+    }
+}
@@ -90,7 +90,7 @@ impl EntityManager {
             .count()
     }
 
-    pub fn entities(&self) -> FilteredEntities {
+    pub fn entities(&self) -> FilteredEntities<'_> {
         self.entities.iter().filter_map(|(e, a)| a.then_some(*e))
     }
 
@@ -1,16 +1,20 @@
 pub mod entity_manager;
 pub mod renderer_manager;
+pub mod resource_manager;
 pub mod system_manager;
 
 pub use entity_manager::EntityManager;
 pub use renderer_manager::RendererManager;
+pub use resource_manager::ResourceManager;
 pub use system_manager::SystemManager;
 
+use crate::resources::{DebugDraw, PostProcess, SpriteStats, Time, Ui};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
 pub struct World {
     pub em: Arc<RwLock<EntityManager>>,
+    pub res: Arc<RwLock<ResourceManager>>,
     pub(crate) sm: Arc<RwLock<SystemManager>>,
     pub(crate) rm: Arc<RwLock<RendererManager>>,
 }
@@ -21,8 +25,21 @@ impl World {
         sm: SystemManager,
         rm: RendererManager,
     ) -> Arc<RwLock<Self>> {
+        let res = ResourceManager::new();
+
+        {
+            let mut res = res.write();
+
+            res.add(Time::new());
+            res.add(SpriteStats::new());
+            res.add(DebugDraw::new());
+            res.add(Ui::new());
+            res.add(PostProcess::new());
+        }
+
         Arc::new(RwLock::new(Self {
             em,
+            res,
             sm: Arc::new(RwLock::new(sm)),
             rm: Arc::new(RwLock::new(rm)),
         }))
@@ -1,4 +1,4 @@
-use crate::Control;
+use crate::{Control, Id};
 use parking_lot::RwLock;
 use std::sync::Arc;
 use vulkano::command_buffer::{
@@ -13,4 +13,5 @@ pub type Draw<'a> = (
         Arc<StandardCommandBufferAllocator>,
     >,
     bool,
+    Option<Id>,
 );
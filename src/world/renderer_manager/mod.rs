@@ -2,9 +2,9 @@ pub mod draw;
 pub mod renderer;
 
 pub use draw::Draw;
-pub use renderer::Renderer;
+pub use renderer::{DrawItem, Renderer};
 
-use crate::{Context, World};
+use crate::{renderers::layering, Context, World};
 
 use parking_lot::RwLock;
 use std::sync::Arc;
@@ -37,10 +37,33 @@ impl RendererManager {
         context: Arc<RwLock<Context>>,
         world: Arc<RwLock<World>>,
     ) -> anyhow::Result<()> {
+        let mut items = Vec::new();
+
+        for r in &mut *self.renderers {
+            items.extend(r.items(draw, context.clone(), world.clone())?);
+        }
+
+        for item in layering::sort(items) {
+            item(draw, context.clone(), world.clone())?;
+        }
+
         for r in &mut *self.renderers {
             r.draw(draw, context.clone(), world.clone())?;
         }
 
         Ok(())
     }
+
+    pub fn overlay(
+        &mut self,
+        draw: &mut Draw,
+        context: Arc<RwLock<Context>>,
+        world: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        for r in &mut *self.renderers {
+            r.overlay(draw, context.clone(), world.clone())?;
+        }
+
+        Ok(())
+    }
 }
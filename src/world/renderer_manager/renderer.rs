@@ -2,7 +2,20 @@ use super::{Context, Draw, World};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
+pub type DrawFn =
+    Box<dyn FnOnce(&mut Draw, Arc<RwLock<Context>>, Arc<RwLock<World>>) -> anyhow::Result<()>>;
+pub type DrawItem = (bool, u32, f32, DrawFn);
+
 pub trait Renderer: Send + Sync + 'static {
+    fn items(
+        &mut self,
+        _: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        _: Arc<RwLock<World>>,
+    ) -> anyhow::Result<Vec<DrawItem>> {
+        Ok(Vec::new())
+    }
+
     fn draw(
         &mut self,
         _: &mut Draw,
@@ -11,4 +24,13 @@ pub trait Renderer: Send + Sync + 'static {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn overlay(
+        &mut self,
+        _: &mut Draw,
+        _: Arc<RwLock<Context>>,
+        _: Arc<RwLock<World>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
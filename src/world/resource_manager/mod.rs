@@ -0,0 +1,33 @@
+use parking_lot::RwLock;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+#[derive(Default)]
+pub struct ResourceManager {
+    resources: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl ResourceManager {
+    pub fn new() -> Arc<RwLock<Self>> {
+        Arc::new(RwLock::new(Default::default()))
+    }
+
+    pub fn add<R: Send + Sync + 'static>(&mut self, resource: Arc<RwLock<R>>) {
+        self.resources.insert(TypeId::of::<R>(), resource);
+    }
+
+    pub fn rm<R: Send + Sync + 'static>(&mut self) {
+        self.resources.remove(&TypeId::of::<R>());
+    }
+
+    pub fn get<R: Send + Sync + 'static>(&self) -> Option<Arc<RwLock<R>>> {
+        self.resources
+            .get(&TypeId::of::<R>())?
+            .clone()
+            .downcast::<RwLock<R>>()
+            .ok()
+    }
+}